@@ -0,0 +1,545 @@
+use embedded_hal::digital::{ErrorType, OutputPin};
+
+use crate::{Channel, Clock};
+
+/// Definition of an alarm monitoring the values of a single channel.
+///
+/// An alarm raises when its [`AlarmCondition`] is violated by `debounce` consecutive samples and
+/// clears when the condition is met again by `debounce` consecutive samples including the
+/// hysteresis. Alarms are evaluated on the values in millivolts, i.e. thresholds and hysteresis are
+/// specified in mV and rates in mV/s.
+///
+/// * The hysteresis specifies the margin by which a value has to return below a high threshold,
+///   above a low threshold or below a rate to clear the alarm. Non-finite values are ignored.
+///   **Default:** `0`
+/// * The debounce specifies the number of consecutive samples required to raise or to clear the
+///   alarm. **Default:** `1`
+///
+/// # Example
+///
+/// ```
+/// use mcp3424::{Alarm, Channel};
+///
+/// let over_voltage = Alarm::high(Channel::Channel1, 1800.0)
+///     .with_hysteresis(50.0)
+///     .with_debounce(3);
+/// let under_voltage = Alarm::low(Channel::Channel1, 200.0);
+/// let surge = Alarm::rate_of_change(Channel::Channel2, 1000.0);
+/// ```
+///
+/// # See also
+/// [`AlarmMonitor`]
+///
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Alarm {
+    pub channel: Channel,
+    pub condition: AlarmCondition,
+    pub hysteresis: f32,
+    pub debounce: u8,
+}
+
+impl Alarm {
+
+    pub fn new(channel: Channel, condition: AlarmCondition) -> Self {
+        Self { channel, condition, hysteresis: 0.0, debounce: 1 }
+    }
+
+    /// Creates an alarm raising when a value exceeds the specified threshold in mV.
+    pub fn high(channel: Channel, threshold: f32) -> Self {
+        Self::new(channel, AlarmCondition::Above(threshold))
+    }
+
+    /// Creates an alarm raising when a value falls below the specified threshold in mV.
+    pub fn low(channel: Channel, threshold: f32) -> Self {
+        Self::new(channel, AlarmCondition::Below(threshold))
+    }
+
+    /// Creates an alarm raising when the values change faster than the specified rate in mV/s.
+    pub fn rate_of_change(channel: Channel, rate: f32) -> Self {
+        Self::new(channel, AlarmCondition::RateAbove(rate))
+    }
+
+    pub fn with_hysteresis(mut self, hysteresis: f32) -> Self {
+        if hysteresis.is_finite() {
+            self.hysteresis = hysteresis.max(-hysteresis);
+        }
+        self
+    }
+
+    pub fn with_debounce(mut self, debounce: u8) -> Self {
+        self.debounce = debounce.max(1);
+        self
+    }
+}
+
+/// The condition of an [`Alarm`].
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmCondition {
+    /// Violated by values above the specified threshold in mV.
+    Above(f32),
+    /// Violated by values below the specified threshold in mV.
+    Below(f32),
+    /// Violated if the absolute rate of change between two consecutive samples of the channel
+    /// exceeds the specified rate in mV/s.
+    RateAbove(f32),
+}
+
+/// A transition of an [`Alarm`], reported by the [`AlarmMonitor`].
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmEvent {
+    /// The index of the alarm as passed to [`AlarmMonitor::new`].
+    pub alarm: usize,
+    pub channel: Channel,
+    pub transition: AlarmTransition,
+    /// The time of the transition in µs according to the monitor's [`Clock`].
+    pub timestamp: u64,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmTransition {
+    Raised,
+    Cleared,
+}
+
+/// An output which is not connected, see [`AlarmMonitor::with_output`].
+pub struct NoOutput;
+
+impl ErrorType for NoOutput {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for NoOutput {
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Monitor evaluating a set of [`Alarm`]s on every sample.
+///
+/// The monitor is fed with the values of any mode via [`Self::evaluate`] and reports each raising
+/// and clearing alarm as [`AlarmEvent`] to a callback. With the `stream` feature, a stream of
+/// measured values can be turned into a stream of events via [`Self::watch`]. Optionally, the
+/// monitor drives an [`OutputPin`], which is set high while any alarm is raised.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Alarm, AlarmMonitor, Channel, Clock, Configuration, OneShotMode};
+///
+///# struct Timer;
+///# impl Clock for Timer { fn now_us(&self) -> u64 { 0 } }
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let mut monitor = AlarmMonitor::new(Timer, [
+///     Alarm::high(Channel::Channel1, 1800.0).with_hysteresis(50.0),
+///     Alarm::low(Channel::Channel1, 200.0),
+/// ]);
+///
+///# async_std::task::block_on(async {
+/// if let Ok(value) = adc.measure().await {
+///     monitor.evaluate(Channel::Channel1, value, |event| println!("Alarm {} changed", event.alarm));
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+pub struct AlarmMonitor<C: Clock, const N: usize, Pin: OutputPin = NoOutput> {
+    clock: C,
+    output: Pin,
+    alarms: [Alarm; N],
+    states: [State; N],
+    previous: [Option<Sample>; 4],
+}
+
+#[derive(Copy, Clone, Default)]
+struct State {
+    raised: bool,
+    count: u8,
+}
+
+#[derive(Copy, Clone)]
+struct Sample {
+    value: f32,
+    timestamp: u64,
+}
+
+impl <C: Clock, const N: usize> AlarmMonitor<C, N> {
+
+    pub fn new(clock: C, alarms: [Alarm; N]) -> Self {
+        Self {
+            clock,
+            output: NoOutput,
+            alarms,
+            states: [State::default(); N],
+            previous: [None; 4],
+        }
+    }
+
+    /// Uses the specified pin as output, which is set high when an alarm raises and set low when
+    /// all alarms have been cleared.
+    pub fn with_output<Pin: OutputPin>(self, output: Pin) -> AlarmMonitor<C, N, Pin> {
+        AlarmMonitor {
+            clock: self.clock,
+            output,
+            alarms: self.alarms,
+            states: self.states,
+            previous: self.previous,
+        }
+    }
+}
+
+impl <C: Clock, const N: usize, Pin: OutputPin> AlarmMonitor<C, N, Pin> {
+
+    /// Returns whether the alarm at the specified index is raised.
+    pub fn is_raised(&self, index: usize) -> bool {
+        self.states.get(index).is_some_and(|state| state.raised)
+    }
+
+    /// Returns whether any alarm is raised.
+    pub fn any_raised(&self) -> bool {
+        self.states.iter().any(|state| state.raised)
+    }
+
+    /// Clears all alarms without reporting events, forgets the previous samples and sets the
+    /// output low.
+    ///
+    /// Returns the error of the output, if setting its level fails.
+    pub fn reset(&mut self) -> Result<(), Pin::Error> {
+        self.states = [State::default(); N];
+        self.previous = [None; 4];
+        self.output.set_low()
+    }
+
+    /// Returns the clock and the output.
+    pub fn into_parts(self) -> (C, Pin) {
+        (self.clock, self.output)
+    }
+
+    /// Evaluates all alarms of the specified channel on the specified value and passes every
+    /// transition to the specified callback.
+    ///
+    /// Returns the error of the output, if setting its level fails.
+    #[cfg(not(feature = "uom"))]
+    pub fn evaluate(&mut self, channel: Channel, value: f32, callback: impl FnMut(AlarmEvent)) -> Result<(), Pin::Error> {
+        self.do_evaluate(channel, value, callback)
+    }
+
+    /// Evaluates all alarms of the specified channel on the specified value and passes every
+    /// transition to the specified callback.
+    ///
+    /// Returns the error of the output, if setting its level fails.
+    #[cfg(feature = "uom")]
+    pub fn evaluate(&mut self, channel: Channel, value: uom::si::f32::ElectricPotential, callback: impl FnMut(AlarmEvent)) -> Result<(), Pin::Error> {
+        self.do_evaluate(channel, value.get::<uom::si::electric_potential::millivolt>(), callback)
+    }
+
+    /// Returns a stream of the transitions of all alarms of the specified channel, evaluated on the
+    /// values of the specified stream, e.g. of [`ContinuousMode::measure_fresh_stream`].
+    ///
+    /// Errors of the specified stream are passed through. If setting the level of the output
+    /// fails, an [`Error::PinError`] will be returned by the stream.
+    ///
+    /// [`ContinuousMode::measure_fresh_stream`]: crate::MCP3424::measure_fresh_stream
+    /// [`Error::PinError`]: crate::Error::PinError
+    #[cfg(all(feature = "stream", not(feature = "uom")))]
+    pub fn watch<'a, BusError: embedded_hal_async::i2c::Error + 'a>(&'a mut self, channel: Channel, samples: impl futures::Stream<Item=Result<f32, crate::Error<BusError>>> + 'a) -> impl futures::Stream<Item=Result<AlarmEvent, crate::Error<BusError>>> + 'a {
+        self.do_watch(channel, samples)
+    }
+
+    /// Returns a stream of the transitions of all alarms of the specified channel, evaluated on the
+    /// values of the specified stream, e.g. of [`ContinuousMode::measure_fresh_stream`].
+    ///
+    /// Errors of the specified stream are passed through. If setting the level of the output
+    /// fails, an [`Error::PinError`] will be returned by the stream.
+    ///
+    /// [`ContinuousMode::measure_fresh_stream`]: crate::MCP3424::measure_fresh_stream
+    /// [`Error::PinError`]: crate::Error::PinError
+    #[cfg(all(feature = "stream", feature = "uom"))]
+    pub fn watch<'a, BusError: embedded_hal_async::i2c::Error + 'a>(&'a mut self, channel: Channel, samples: impl futures::Stream<Item=Result<uom::si::f32::ElectricPotential, crate::Error<BusError>>> + 'a) -> impl futures::Stream<Item=Result<AlarmEvent, crate::Error<BusError>>> + 'a {
+        use futures::StreamExt;
+        self.do_watch(channel, samples
+            .map(|result| result.map(|value| value.get::<uom::si::electric_potential::millivolt>())))
+    }
+
+    #[cfg(feature = "stream")]
+    fn do_watch<'a, BusError: embedded_hal_async::i2c::Error + 'a>(&'a mut self, channel: Channel, samples: impl futures::Stream<Item=Result<f32, crate::Error<BusError>>> + 'a) -> impl futures::Stream<Item=Result<AlarmEvent, crate::Error<BusError>>> + 'a {
+        use futures::StreamExt;
+        samples.flat_map(move |result| {
+            let mut events = [None; N];
+            let mut error = None;
+            match result {
+                Ok(value) => {
+                    let mut count = 0;
+                    if let Err(cause) = self.do_evaluate(channel, value, |event| {
+                        events[count] = Some(event);
+                        count += 1;
+                    }) {
+                        error = Some(crate::Error::PinError(embedded_hal::digital::Error::kind(&cause)));
+                    }
+                }
+                Err(cause) => error = Some(cause),
+            }
+            futures::stream::iter(events.into_iter().flatten().map(Ok).chain(error.map(Err)))
+        })
+    }
+
+    fn do_evaluate(&mut self, channel: Channel, value: f32, mut callback: impl FnMut(AlarmEvent)) -> Result<(), Pin::Error> {
+
+        let timestamp = self.clock.now_us();
+        let previous = self.previous[channel.mask() as usize].replace(Sample { value, timestamp });
+
+        let rate = previous
+            .filter(|previous| timestamp > previous.timestamp)
+            .map(|previous| (value - previous.value).max(previous.value - value) * 1_000_000.0 / (timestamp - previous.timestamp) as f32);
+
+        let raised = self.any_raised();
+
+        for (index, (alarm, state)) in self.alarms.iter().zip(self.states.iter_mut()).enumerate() {
+
+            if alarm.channel.mask() != channel.mask() {
+                continue
+            }
+
+            let (violated, recovered) = match alarm.condition {
+                AlarmCondition::Above(threshold) => (value > threshold, value <= threshold - alarm.hysteresis),
+                AlarmCondition::Below(threshold) => (value < threshold, value >= threshold + alarm.hysteresis),
+                AlarmCondition::RateAbove(limit) => match rate {
+                    Some(rate) => (rate > limit, rate <= limit - alarm.hysteresis),
+                    None => continue,
+                },
+            };
+
+            let approaching = if state.raised { recovered } else { violated };
+
+            if !approaching {
+                state.count = 0;
+                continue
+            }
+
+            state.count = state.count.saturating_add(1);
+
+            if state.count >= alarm.debounce {
+                state.raised = !state.raised;
+                state.count = 0;
+                callback(AlarmEvent {
+                    alarm: index,
+                    channel,
+                    transition: if state.raised { AlarmTransition::Raised } else { AlarmTransition::Cleared },
+                    timestamp,
+                });
+            }
+        }
+
+        if raised != self.any_raised() {
+            if !raised {
+                self.output.set_high()?;
+            }
+            else {
+                self.output.set_low()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::cell::Cell;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use embedded_hal_mock::eh1::digital::{Mock as Pin, State as PinState, Transaction as PinTransaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{Alarm, AlarmEvent, AlarmMonitor, AlarmTransition, Channel};
+    use crate::test_util::TestClock;
+
+    /// Evaluates the specified values in mV, one per millisecond, and returns the transitions.
+    fn evaluate<const N: usize>(testee: &mut AlarmMonitor<TestClock, N, impl embedded_hal::digital::OutputPin>, time: &Cell<u64>, channel: Channel, values: &[f32]) -> Vec<(usize, AlarmTransition)> {
+        let mut events = Vec::new();
+        for value in values {
+            time.set(time.get() + 1000);
+            #[cfg(not(feature = "uom"))]
+            let value = *value;
+            #[cfg(feature = "uom")]
+            let value = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(*value);
+            let _ = testee.evaluate(channel, value, |event: AlarmEvent| events.push((event.alarm, event.transition)));
+        }
+        events
+    }
+
+    #[rstest]
+    fn An_AlarmMonitor_should_raise_and_clear_alarms_with_hysteresis() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::high(Channel::Channel1, 100.0).with_hysteresis(10.0),
+            Alarm::low(Channel::Channel1, 0.0),
+            Alarm::high(Channel::Channel2, 100.0),
+        ]);
+
+        assert_that!(evaluate(&mut testee, &time, Channel::Channel1, &[50.0, 101.0, 95.0, 89.0, -1.0]), eq(&vec![
+            (0, AlarmTransition::Raised),
+            (0, AlarmTransition::Cleared),
+            (1, AlarmTransition::Raised),
+        ]));
+        assert_that!(testee.is_raised(1), eq(true));
+        assert_that!(testee.is_raised(2), eq(false));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AlarmMonitor_should_debounce_alarms() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::high(Channel::Channel1, 100.0).with_debounce(2),
+        ]);
+
+        assert_that!(evaluate(&mut testee, &time, Channel::Channel1, &[101.0, 50.0, 101.0]), empty());
+        assert_that!(evaluate(&mut testee, &time, Channel::Channel1, &[101.0, 50.0, 101.0, 50.0, 50.0]), eq(&vec![
+            (0, AlarmTransition::Raised),
+            (0, AlarmTransition::Cleared),
+        ]));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AlarmMonitor_should_raise_alarms_on_the_rate_of_change() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::rate_of_change(Channel::Channel3, 1000.0),
+        ]);
+
+        // Samples are 1ms apart, i.e. a step of 2 mV is a rate of 2000 mV/s.
+        assert_that!(evaluate(&mut testee, &time, Channel::Channel3, &[0.0, 0.5, 2.5, 3.0]), eq(&vec![
+            (0, AlarmTransition::Raised),
+            (0, AlarmTransition::Cleared),
+        ]));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AlarmMonitor_should_drive_its_output_while_any_alarm_is_raised() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let pin = Pin::new(&[
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ]);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::high(Channel::Channel1, 100.0),
+            Alarm::high(Channel::Channel1, 200.0),
+        ]).with_output(pin);
+
+        evaluate(&mut testee, &time, Channel::Channel1, &[150.0, 250.0, 150.0, 50.0]);
+
+        testee.into_parts().1.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AlarmMonitor_should_set_its_output_low_on_reset() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let pin = Pin::new(&[
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ]);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::high(Channel::Channel1, 100.0),
+        ]).with_output(pin);
+
+        evaluate(&mut testee, &time, Channel::Channel1, &[150.0]);
+
+        assert_that!(testee.reset(), ok(anything()));
+        assert_that!(testee.any_raised(), eq(false));
+
+        testee.into_parts().1.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_Alarm_should_ignore_a_non_finite_hysteresis() -> Result<()> {
+
+        let alarm = Alarm::high(Channel::Channel1, 100.0)
+            .with_hysteresis(-10.0)
+            .with_hysteresis(f32::NAN)
+            .with_hysteresis(f32::INFINITY);
+
+        assert_that!(alarm.hysteresis, eq(10.0));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "stream")]
+    #[rstest]
+    async fn An_AlarmMonitor_should_turn_a_stream_of_values_into_a_stream_of_events() -> Result<()> {
+        use futures::StreamExt;
+
+        use crate::Error;
+
+        let time = Cell::new(0);
+
+        let mut testee = AlarmMonitor::new(TestClock(&time), [
+            Alarm::high(Channel::Channel1, 100.0),
+            Alarm::low(Channel::Channel1, 120.0),
+        ]);
+
+        #[cfg(not(feature = "uom"))]
+        let samples = futures::stream::iter([Ok(110.0), Err(Error::<embedded_hal::i2c::ErrorKind>::NotReady), Ok(130.0)]);
+        #[cfg(feature = "uom")]
+        let samples = futures::stream::iter([Ok(110.0), Err(Error::<embedded_hal::i2c::ErrorKind>::NotReady), Ok(130.0)])
+            .map(|result| result.map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>));
+
+        let events = testee.watch(Channel::Channel1, samples)
+            .map(|result| result.map(|event| (event.alarm, event.transition)))
+            .collect::<Vec<_>>().await;
+
+        assert_that!(events, elements_are![
+            ok(eq(&(0, AlarmTransition::Raised))),
+            ok(eq(&(1, AlarmTransition::Raised))),
+            err(matches_pattern!(Error::NotReady)),
+            ok(eq(&(1, AlarmTransition::Cleared))),
+        ]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,501 @@
+use core::marker::PhantomData;
+
+use embedded_hal_async::i2c::SevenBitAddress;
+
+use crate::{Channel, Configuration, Error, MCP3424, OneShotMode};
+use crate::cfg::Cfg;
+use crate::mode::oneshot_cfg;
+
+/// Driver for several MCP3424 on one bus, treated as a single logical ADC.
+///
+/// The array owns the bus and the addresses of `D` devices and exposes their channels as one
+/// channel list. Each entry of the list names the index of a device and the configuration of one of
+/// its channels, the values are reported in the order of the list. To measure the channel list, the
+/// array converts the same channel on all devices at once, so that the total scan time is the one
+/// of a single device.
+///
+/// **Default:** The channel list contains all `D × 4` channels using the default configuration,
+/// whereby the channels of the device at index `d` are found at the indices `d * 4` to `d * 4 + 3`.
+///
+/// **Default:** The conversions are started simultaneously by a general call.
+///
+/// **Default:** Each device assumes the nominal reference voltage of 2.048 V, which can be adjusted
+/// per device like the one of a [`MCP3424`], see [`Self::set_reference_voltage`] and
+/// [`Self::calibrate`].
+///
+/// <div class="warning">
+/// <b>Important</b>
+///
+/// A general call is received by all devices on the bus which support it. Use
+/// [`Synchronization::Staggered`] if other devices on the bus must not receive a general call.
+/// </div>
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424Array, Channel, Configuration, Error, Synchronization};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::write(0x69, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::read(0x69, vec![0, 2, 0, 0]),
+///#     Transaction::write(0x69, vec![0b10100000]),
+///#     Transaction::read(0x69, vec![0, 3, 0b00100000, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424Array::new(i2c, [0x68, 0x69], Delay)
+///     .with_synchronization(Synchronization::Staggered);
+///
+///# let _: Result<(), Error<_>> = async_std::task::block_on(async {
+/// adc.configure(&[
+///     (0, Configuration::default().with_channel(Channel::Channel1)),
+///     (1, Configuration::default().with_channel(Channel::Channel1)),
+///     (1, Configuration::default().with_channel(Channel::Channel2)),
+/// ])?;
+///
+/// let mut values = [Default::default(); 3];
+///
+/// let count = adc.measure(&mut values).await?;
+/// println!("Measured values: {:?}", &values[..count]);
+///# Ok(())
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+pub struct MCP3424Array<I2c, BusError, Delay, const D: usize> {
+    i2c: I2c,
+    addresses: [SevenBitAddress; D],
+    delay: Delay,
+    slots: [[Option<Slot>; 4]; D],
+    references: [i64; D],
+    len: usize,
+    synchronization: Synchronization,
+    _phantom: PhantomData<BusError>
+}
+
+/// A channel of a device in the channel list.
+#[derive(Copy, Clone)]
+struct Slot {
+    cfg: Cfg,
+    delay: u32,
+    position: usize,
+}
+
+type Device<I2c, BusError, Delay> = MCP3424<I2c, BusError, Delay, OneShotMode>;
+
+/// The way the conversions of the devices of a [`MCP3424Array`] are started.
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Synchronization {
+    /// All devices are configured first and start converting at once on a general call.
+    Simultaneous,
+    /// Each device starts converting as soon as it has been configured.
+    Staggered,
+}
+
+impl <I2c, BusError, Delay, const D: usize> MCP3424Array<I2c, BusError, Delay, D>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    /// The general call address.
+    const GENERAL_CALL: SevenBitAddress = 0x00;
+
+    /// The general call command to start a conversion.
+    const GENERAL_CALL_CONVERSION: u8 = 0x08;
+
+    pub fn new(i2c: I2c, addresses: [SevenBitAddress; D], delay: Delay) -> Self {
+        let mut slots = [[None; 4]; D];
+        for (device, channels) in slots.iter_mut().enumerate() {
+            for (index, channel) in [Channel::Channel1, Channel::Channel2, Channel::Channel3, Channel::Channel4].into_iter().enumerate() {
+                channels[index] = Some(Slot::new(&Configuration::default().with_channel(channel), device * 4 + index));
+            }
+        }
+        Self {
+            i2c,
+            addresses,
+            delay,
+            slots,
+            references: [Device::<I2c, BusError, Delay>::REFERENCE_VOLTAGE; D],
+            len: D * 4,
+            synchronization: Synchronization::Simultaneous,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the way the conversions of the devices are started.
+    pub fn with_synchronization(mut self, synchronization: Synchronization) -> Self {
+        self.synchronization = synchronization;
+        self
+    }
+
+    /// Returns the number of channels in the channel list.
+    pub fn channel_count(&self) -> usize {
+        self.len
+    }
+
+    /// Replaces the channel list by the specified entries, each naming the index of a device and
+    /// the configuration of one of its channels.
+    ///
+    /// If an entry refers to a device which does not exist, an [`Error::InvalidIndex`] naming the
+    /// entry's device index will be returned. If an entry refers to a channel already in the list,
+    /// an [`Error::DuplicateChannel`] naming the entry's index will be returned. In both cases, the
+    /// channel list remains unchanged.
+    ///
+    pub fn configure(&mut self, channels: &[(usize, Configuration)]) -> Result<(), Error<BusError>> {
+        let mut slots = [[None; 4]; D];
+        for (position, (device, configuration)) in channels.iter().enumerate() {
+            let slot = slots.get_mut(*device)
+                .map(|channels| &mut channels[configuration.channel.mask() as usize])
+                .ok_or(Error::InvalidIndex { index: *device, len: D })?;
+            if slot.is_some() {
+                return Err(Error::DuplicateChannel { index: position })
+            }
+            *slot = Some(Slot::new(configuration, position));
+        }
+        self.slots = slots;
+        self.len = channels.len();
+        Ok(())
+    }
+
+    /// Sets the reference voltage assumed to convert output codes of the device at the specified
+    /// index.
+    ///
+    /// Non-finite or non-positive values are ignored, values above 4.096 V are clamped. If the device
+    /// does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub fn set_reference_voltage(&mut self, device: usize, millivolts: f32) -> Result<(), Error<BusError>> {
+        self.update_reference(device, |_| Device::<I2c, BusError, Delay>::limit_reference(millivolts as f64 * 1_000_000.0))
+    }
+
+    /// Sets the reference voltage assumed to convert output codes of the device at the specified
+    /// index.
+    ///
+    /// Non-finite or non-positive values are ignored, values above 4.096 V are clamped. If the device
+    /// does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub fn set_reference_voltage(&mut self, device: usize, voltage: uom::si::f32::ElectricPotential) -> Result<(), Error<BusError>> {
+        let millivolts = voltage.get::<uom::si::electric_potential::millivolt>();
+        self.update_reference(device, |_| Device::<I2c, BusError, Delay>::limit_reference(millivolts as f64 * 1_000_000.0))
+    }
+
+    /// Returns the reference voltage assumed to convert output codes of the device at the specified
+    /// index, if the device exists.
+    #[cfg(not(feature = "uom"))]
+    pub fn reference_voltage(&self, device: usize) -> Option<f32> {
+        self.references.get(device)
+            .map(|reference| (*reference as f64 / 1_000_000.0) as f32)
+    }
+
+    /// Returns the reference voltage assumed to convert output codes of the device at the specified
+    /// index, if the device exists.
+    #[cfg(feature = "uom")]
+    pub fn reference_voltage(&self, device: usize) -> Option<uom::si::f32::ElectricPotential> {
+        self.references.get(device)
+            .map(|reference| uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>((*reference as f64 / 1_000_000.0) as f32))
+    }
+
+    /// Adjusts the assumed reference voltage of the device at the specified index, so that a value
+    /// measured with the current reference voltage matches the expected value, see
+    /// [`MCP3424::calibrate`].
+    ///
+    /// If the device does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub fn calibrate(&mut self, device: usize, measured: f32, expected: f32) -> Result<(), Error<BusError>> {
+        self.update_reference(device, |reference| Device::<I2c, BusError, Delay>::calibrated_reference(reference, measured, expected))
+    }
+
+    /// Adjusts the assumed reference voltage of the device at the specified index, so that a value
+    /// measured with the current reference voltage matches the expected value, see
+    /// [`MCP3424::calibrate`].
+    ///
+    /// If the device does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub fn calibrate(&mut self, device: usize, measured: uom::si::f32::ElectricPotential, expected: uom::si::f32::ElectricPotential) -> Result<(), Error<BusError>> {
+        use uom::si::electric_potential::millivolt;
+        let (measured, expected) = (measured.get::<millivolt>(), expected.get::<millivolt>());
+        self.update_reference(device, |reference| Device::<I2c, BusError, Delay>::calibrated_reference(reference, measured, expected))
+    }
+
+    fn update_reference(&mut self, device: usize, update: impl FnOnce(i64) -> Option<i64>) -> Result<(), Error<BusError>> {
+        let reference = self.references.get_mut(device)
+            .ok_or(Error::InvalidIndex { index: device, len: D })?;
+        if let Some(updated) = update(*reference) {
+            *reference = updated;
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> (I2c, Delay) {
+        (self.i2c, self.delay)
+    }
+
+    /// Measures all channels of the channel list and writes the results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small, an
+    /// [`Error::CapacityExceeded`] will be returned. If a device returns data of another channel
+    /// than the configured one, an [`Error::NotReady`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self, values: &mut [f32]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = value).await
+    }
+
+    /// Measures all channels of the channel list and writes the results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small, an
+    /// [`Error::CapacityExceeded`] will be returned. If a device returns data of another channel
+    /// than the configured one, an [`Error::NotReady`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self, values: &mut [uom::si::f32::ElectricPotential]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value)).await
+    }
+
+    fn check_buffer(&self, capacity: usize) -> Result<(), Error<BusError>> {
+        if capacity < self.len {
+            Err(Error::CapacityExceeded { capacity })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    async fn do_measure(&mut self, mut store: impl FnMut(usize, f32)) -> Result<usize, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        for channel in 0..4 {
+
+            if self.slots.iter().all(|slots| slots[channel].is_none()) {
+                continue
+            }
+
+            let mut delay = 0;
+
+            for device in 0..D {
+                let Some(slot) = self.slots[device][channel] else {
+                    continue
+                };
+                let mut cfg = slot.cfg;
+                if let Synchronization::Simultaneous = self.synchronization {
+                    // Configures the device without starting a conversion.
+                    cfg.ready = true;
+                }
+                self.i2c.write(self.addresses[device], &[cfg.as_byte()]).await?;
+                delay = delay.max(slot.delay);
+            }
+
+            if let Synchronization::Simultaneous = self.synchronization {
+                self.i2c.write(Self::GENERAL_CALL, &[Self::GENERAL_CALL_CONVERSION]).await?;
+            }
+
+            self.delay.delay_us(delay).await;
+
+            for device in 0..D {
+                let Some(slot) = self.slots[device][channel] else {
+                    continue
+                };
+                self.i2c.read(self.addresses[device], &mut buffer).await?;
+                store(slot.position, Device::<I2c, BusError, Delay>::convert_channel(&buffer, &slot.cfg, self.references[device])?);
+            }
+        }
+
+        Ok(self.len)
+    }
+}
+
+impl Slot {
+    fn new(configuration: &Configuration, position: usize) -> Self {
+        Self {
+            cfg: oneshot_cfg(configuration, Cfg::default()),
+            delay: configuration.conversion_time_us(),
+            position,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Channel, Configuration, Error, MCP3424Array, Resolution};
+
+    #[rstest]
+    async fn A_MCP3424Array_should_start_the_conversions_of_all_devices_simultaneously() -> Result<()> {
+
+        let mut transactions = Vec::new();
+
+        for channel in 0..4_u8 {
+            let cfg = channel << 5;
+            transactions.push(Transaction::write(0x68, vec![cfg | 0b00000100]));
+            transactions.push(Transaction::write(0x69, vec![cfg]));
+            transactions.push(Transaction::write(0x00, vec![0x08]));
+            transactions.push(Transaction::read(0x68, vec![0, channel + 1, cfg | 0b00000100, 0]));
+            transactions.push(Transaction::read(0x69, vec![0, channel + 5, cfg, 0]));
+        }
+
+        let mut testee = MCP3424Array::new(I2c::new(&transactions), [0x68, 0x69], NoopDelay);
+
+        let channels = [Channel::Channel1, Channel::Channel2, Channel::Channel3, Channel::Channel4];
+
+        let mut list = Vec::new();
+        list.extend(channels.map(|channel| (0, Configuration::default().with_channel(channel).with_resolution(Resolution::FourteenBits))));
+        list.extend(channels.map(|channel| (1, Configuration::default().with_channel(channel))));
+
+        testee.configure(&list)?;
+
+        let mut values = [Default::default(); 8];
+
+        assert_that!(testee.channel_count(), eq(8));
+        assert_that!(testee.measure(&mut values).await, ok(eq(&8)));
+        assert_that!(testee.measure(&mut values[1..]).await, err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&7) })));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(values, eq([1.0 / 4.0, 2.0 / 4.0, 3.0 / 4.0, 4.0 / 4.0, 5.0, 6.0, 7.0, 8.0]));
+
+        testee.into_inner().0.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424Array_should_convert_only_the_channels_of_the_channel_list_in_its_order() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x69, vec![0b00000000]),
+            Transaction::write(0x00, vec![0x08]),
+            Transaction::read(0x69, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b01000000]),
+            Transaction::write(0x00, vec![0x08]),
+            Transaction::read(0x68, vec![0, 2, 0b01000000, 0]),
+        ]);
+
+        let mut testee = MCP3424Array::new(i2c, [0x68, 0x69], NoopDelay);
+
+        testee.configure(&[
+            (0, Configuration::default().with_channel(Channel::Channel3)),
+            (1, Configuration::default().with_channel(Channel::Channel1)),
+        ])?;
+
+        let mut values = [Default::default(); 2];
+
+        assert_that!(testee.channel_count(), eq(2));
+        assert_that!(testee.measure(&mut values).await, ok(eq(&2)));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(values, eq([2.0, 1.0]));
+
+        testee.into_inner().0.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424Array_should_return_an_error_if_a_device_returns_data_of_another_channel() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00100000]),
+            Transaction::write(0x00, vec![0x08]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+        ]);
+
+        let mut testee = MCP3424Array::new(i2c, [0x68], NoopDelay);
+
+        testee.configure(&[(0, Configuration::default().with_channel(Channel::Channel2))])?;
+
+        let mut values = [Default::default(); 1];
+
+        assert_that!(testee.measure(&mut values).await, err(matches_pattern!(Error::NotReady)));
+
+        testee.into_inner().0.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_MCP3424Array_should_reject_invalid_channel_lists() -> Result<()> {
+
+        let mut testee = MCP3424Array::new(I2c::new(&[]), [0x68, 0x69], NoopDelay);
+
+        assert_that!(testee.configure(&[(2, Configuration::default())]), err(matches_pattern!(Error::InvalidIndex { index: eq(&2), len: eq(&2) })));
+        assert_that!(testee.configure(&[(1, Configuration::default()), (0, Configuration::default()), (1, Configuration::default())]), err(matches_pattern!(Error::DuplicateChannel { index: eq(&2) })));
+        assert_that!(testee.channel_count(), eq(8));
+
+        testee.into_inner().0.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424Array_should_convert_with_the_reference_voltage_of_each_device() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00000000]),
+            Transaction::write(0x69, vec![0b00000000]),
+            Transaction::write(0x00, vec![0x08]),
+            Transaction::read(0x68, vec![0, 2, 0b00000000, 0]),
+            Transaction::read(0x69, vec![0, 2, 0b00000000, 0]),
+        ]);
+
+        let mut testee = MCP3424Array::new(i2c, [0x68, 0x69], NoopDelay);
+
+        testee.configure(&[
+            (0, Configuration::default()),
+            (1, Configuration::default()),
+        ])?;
+
+        #[cfg(not(feature = "uom"))]
+        {
+            testee.set_reference_voltage(0, 4096.0)?;
+            testee.calibrate(1, 2.0, 1.0)?;
+            assert_that!(testee.set_reference_voltage(2, 4096.0), err(matches_pattern!(Error::InvalidIndex { index: eq(&2), len: eq(&2) })));
+            assert_that!(testee.reference_voltage(1), some(eq(1024.0)));
+        }
+
+        #[cfg(feature = "uom")]
+        {
+            testee.set_reference_voltage(0, ElectricPotential::new::<millivolt>(4096.0))?;
+            testee.calibrate(1, ElectricPotential::new::<millivolt>(2.0), ElectricPotential::new::<millivolt>(1.0))?;
+            assert_that!(testee.set_reference_voltage(2, ElectricPotential::new::<millivolt>(4096.0)), err(matches_pattern!(Error::InvalidIndex { index: eq(&2), len: eq(&2) })));
+            assert_that!(testee.reference_voltage(1), some(eq(ElectricPotential::new::<millivolt>(1024.0))));
+        }
+
+        let mut values = [Default::default(); 2];
+
+        assert_that!(testee.measure(&mut values).await, ok(eq(&2)));
+
+        #[cfg(feature = "uom")]
+        assert_that!(values, eq([ElectricPotential::new::<millivolt>(4.0), ElectricPotential::new::<millivolt>(1.0)]));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(values, eq([4.0, 1.0]));
+
+        testee.into_inner().0.done();
+
+        Ok(())
+    }
+}
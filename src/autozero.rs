@@ -0,0 +1,239 @@
+use crate::cfg::Cfg;
+use crate::{mode, Channel, Error, Gain, MCP3424, Resolution};
+
+/// Offset correction based on a channel with shorted inputs.
+///
+/// The [`AutoZero`] periodically measures the designated channel, whose inputs are tied together,
+/// with the [`Gain`] and [`Resolution`] of the channel to be corrected and subtracts the tracked
+/// offset from its readings. Offsets are tracked separately for every combination of [`Gain`] and
+/// [`Resolution`] in use.
+///
+/// * The refresh count specifies the number of corrected conversions after which the offset gets
+///   measured again. **Default:** `100`
+/// * The smoothing specifies the weight of a new offset measurement in the range `(0, 1]`, whereby
+///   `1` replaces the tracked offset completely. Non-finite values are ignored. **Default:** `0.25`
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, AutoZero, Configuration, OneShotMode, Channel};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b11100000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 3, 0, 0]),
+///# ]);
+///#
+/// let mut auto_zero = AutoZero::new(Channel::Channel4)
+///     .with_refresh_every(10)
+///     .with_smoothing(0.5);
+///
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+///# async_std::task::block_on(async {
+/// match adc.measure_zeroed(&mut auto_zero).await {
+///     Ok(value) => println!("Corrected value: {:?}", value),
+///     Err(_) => println!("Failed to measure")
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// [`Gain`]: crate::Gain
+/// [`Resolution`]: crate::Resolution
+///
+#[derive(Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AutoZero {
+    channel: Channel,
+    refresh_every: u32,
+    smoothing: f32,
+    offsets: [Option<Offset>; 16],
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Offset {
+    value: f32,
+    age: u32,
+}
+
+impl AutoZero {
+
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            channel,
+            refresh_every: 100,
+            smoothing: 0.25,
+            offsets: [None; 16],
+        }
+    }
+
+    /// Sets the number of corrected conversions after which the offset gets measured again.
+    pub fn with_refresh_every(mut self, conversions: u32) -> Self {
+        self.refresh_every = conversions;
+        self
+    }
+
+    /// Sets the weight of a new offset measurement, clamped to `(0, 1]`.
+    ///
+    /// Non-finite values are rejected, keeping the current smoothing.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        if smoothing.is_finite() {
+            self.smoothing = smoothing.clamp(f32::MIN_POSITIVE, 1.0);
+        }
+        self
+    }
+
+    /// Returns the tracked offset in mV for the specified [`Gain`] and [`Resolution`], if any.
+    pub fn offset(&self, gain: Gain, resolution: Resolution) -> Option<f32> {
+        self.offsets[Self::index(gain, resolution)].map(|offset| offset.value)
+    }
+
+    /// Discards all tracked offsets, forcing a new offset measurement on the next conversion.
+    pub fn reset(&mut self) {
+        self.offsets = [None; 16];
+    }
+
+    fn index(gain: Gain, resolution: Resolution) -> usize {
+        (gain.mask() << 2 | resolution.mask()) as usize
+    }
+
+    pub(crate) fn needs_refresh(&self, cfg: &Cfg) -> bool {
+        match self.offsets[Self::index(cfg.gain, cfg.resolution)] {
+            None => true,
+            Some(offset) => offset.age >= self.refresh_every,
+        }
+    }
+
+    pub(crate) fn update(&mut self, cfg: &Cfg, value: f32) {
+        let offset = &mut self.offsets[Self::index(cfg.gain, cfg.resolution)];
+        *offset = Some(Offset {
+            value: match offset {
+                None => value,
+                Some(offset) => offset.value + self.smoothing * (value - offset.value),
+            },
+            age: 0,
+        });
+    }
+
+    pub(crate) fn correct(&mut self, cfg: &Cfg, value: f32) -> f32 {
+        match &mut self.offsets[Self::index(cfg.gain, cfg.resolution)] {
+            None => value,
+            Some(offset) => {
+                offset.age = offset.age.saturating_add(1);
+                value - offset.value
+            }
+        }
+    }
+}
+
+impl <I2c, BusError, Delay, Mode> MCP3424<I2c, BusError, Delay, Mode>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>,
+    Mode: mode::Mode
+{
+    /// Triggers a single conversion like [`Self::convert_single`] and applies the offset tracked by
+    /// the [`AutoZero`], measuring the offset beforehand if it is due.
+    pub(crate) async fn convert_zeroed(&mut self, cfg: &Cfg, delay: u32, buffer: &mut [u8; 4], auto_zero: &mut AutoZero) -> Result<f32, Error<BusError>> {
+
+        if auto_zero.needs_refresh(cfg) {
+            let zero_cfg = Cfg {
+                channel: auto_zero.channel,
+                ..*cfg
+            };
+            let offset = self.convert_single(&zero_cfg, delay, buffer).await?;
+            auto_zero.update(cfg, offset);
+        }
+
+        let value = self.convert_single(cfg, delay, buffer).await?;
+
+        Ok(auto_zero.correct(cfg, value))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::cfg::Cfg;
+    use crate::{AutoZero, Channel, Gain, Resolution};
+
+    #[rstest]
+    fn An_AutoZero_should_require_a_refresh_after_the_specified_number_of_conversions() -> Result<()> {
+
+        let cfg = Cfg::default();
+
+        let mut testee = AutoZero::new(Channel::Channel4)
+            .with_refresh_every(2);
+
+        verify_that!(testee.needs_refresh(&cfg), eq(true))?;
+
+        testee.update(&cfg, 0.5);
+
+        verify_that!(testee.needs_refresh(&cfg), eq(false))?;
+        verify_that!(testee.correct(&cfg, 2.0), eq(1.5))?;
+        verify_that!(testee.needs_refresh(&cfg), eq(false))?;
+        verify_that!(testee.correct(&cfg, 2.0), eq(1.5))?;
+        verify_that!(testee.needs_refresh(&cfg), eq(true))?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AutoZero_should_smooth_and_separate_offsets() -> Result<()> {
+
+        let cfg = Cfg::default();
+
+        let other_cfg = Cfg {
+            gain: Gain::X8,
+            ..cfg
+        };
+
+        let mut testee = AutoZero::new(Channel::Channel4)
+            .with_smoothing(0.5);
+
+        testee.update(&cfg, 1.0);
+        testee.update(&cfg, 2.0);
+        testee.update(&other_cfg, -1.0);
+
+        verify_that!(testee.offset(Gain::X1, Resolution::TwelveBits), some(eq(1.5)))?;
+        verify_that!(testee.offset(Gain::X8, Resolution::TwelveBits), some(eq(-1.0)))?;
+        verify_that!(testee.offset(Gain::X1, Resolution::EighteenBits), none())?;
+
+        testee.reset();
+
+        verify_that!(testee.offset(Gain::X1, Resolution::TwelveBits), none())?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_AutoZero_should_reject_a_non_finite_smoothing() -> Result<()> {
+
+        let cfg = Cfg::default();
+
+        let mut testee = AutoZero::new(Channel::Channel4)
+            .with_smoothing(0.5)
+            .with_smoothing(f32::NAN)
+            .with_smoothing(f32::INFINITY);
+
+        testee.update(&cfg, 1.0);
+        testee.update(&cfg, 2.0);
+
+        verify_that!(testee.offset(Gain::X1, Resolution::TwelveBits), some(eq(1.5)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use core::cell::RefCell;
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+
+use crate::{Channel, Clock, Configuration, Error, MCP3424, OneShotMode};
+use crate::cfg::Cfg;
+use crate::mode::oneshot_cfg;
+
+/// Cache of the latest value per channel, shared across tasks.
+///
+/// The cache is fed by the acquisition loop of any mode via [`Self::update`] and stores the latest
+/// value together with a timestamp per channel. Consumers read values not older than a maximum age
+/// via [`Self::latest`] without bus traffic, or via [`Self::measure`], which triggers a new
+/// conversion if the cached value is too old.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+/// use mcp3424::{MCP3424, Channel, Clock, Configuration, OneShotMode, ValueCache};
+///
+///# struct Timer;
+///# impl Clock for Timer { fn now_us(&self) -> u64 { 0 } }
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b11000000]),
+///#     Transaction::read(0x68, vec![0, 3, 0b01000000, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let cache = ValueCache::<NoopRawMutex, _>::new(Timer);
+///
+///# async_std::task::block_on(async {
+/// let configuration = Configuration::default().with_channel(Channel::Channel3);
+///
+/// // Converts, as there is no cached value yet.
+/// println!("Value of channel 3: {:?}", cache.measure(&mut adc, &configuration, 500_000).await);
+/// // Served from the cache.
+/// println!("Value of channel 3: {:?}", cache.measure(&mut adc, &configuration, 500_000).await);
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+pub struct ValueCache<M: RawMutex, C: Clock> {
+    clock: C,
+    samples: Mutex<M, RefCell<[Option<Sample>; 4]>>,
+}
+
+#[derive(Copy, Clone)]
+struct Sample {
+    value: f32,
+    timestamp: u64,
+}
+
+impl <M: RawMutex, C: Clock> ValueCache<M, C> {
+
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            samples: Mutex::new(RefCell::new([None; 4])),
+        }
+    }
+
+    /// Stores the specified value as the latest value of the specified channel.
+    #[cfg(not(feature = "uom"))]
+    pub fn update(&self, channel: Channel, value: f32) {
+        self.do_update(channel, value)
+    }
+
+    /// Stores the specified value as the latest value of the specified channel.
+    #[cfg(feature = "uom")]
+    pub fn update(&self, channel: Channel, value: uom::si::f32::ElectricPotential) {
+        self.do_update(channel, value.get::<uom::si::electric_potential::millivolt>())
+    }
+
+    /// Returns the latest value of the specified channel, if it is not older than the specified
+    /// maximum age in µs.
+    #[cfg(not(feature = "uom"))]
+    pub fn latest(&self, channel: Channel, max_age_us: u64) -> Option<f32> {
+        self.do_latest(channel, max_age_us)
+    }
+
+    /// Returns the latest value of the specified channel, if it is not older than the specified
+    /// maximum age in µs.
+    #[cfg(feature = "uom")]
+    pub fn latest(&self, channel: Channel, max_age_us: u64) -> Option<uom::si::f32::ElectricPotential> {
+        self.do_latest(channel, max_age_us)
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Returns the age in µs of the latest value of the specified channel.
+    pub fn age_us(&self, channel: Channel) -> Option<u64> {
+        let now = self.clock.now_us();
+        self.sample(channel)
+            .map(|sample| now.saturating_sub(sample.timestamp))
+    }
+
+    /// Removes all cached values.
+    pub fn clear(&self) {
+        self.samples.lock(|samples| *samples.borrow_mut() = [None; 4])
+    }
+
+    /// Returns the latest value of the channel of the specified configuration, if it is not older
+    /// than the specified maximum age in µs. Otherwise, triggers a single conversion using the
+    /// specified configuration and updates the cache.
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure<I2c, BusError, Delay>(&self, driver: &mut MCP3424<I2c, BusError, Delay, OneShotMode>, configuration: &Configuration, max_age_us: u64) -> Result<f32, Error<BusError>>
+    where
+        I2c: embedded_hal_async::i2c::I2c,
+        BusError: embedded_hal_async::i2c::Error,
+        Delay: embedded_hal_async::delay::DelayNs,
+        Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+    {
+        self.do_measure(driver, configuration, max_age_us).await
+    }
+
+    /// Returns the latest value of the channel of the specified configuration, if it is not older
+    /// than the specified maximum age in µs. Otherwise, triggers a single conversion using the
+    /// specified configuration and updates the cache.
+    #[cfg(feature = "uom")]
+    pub async fn measure<I2c, BusError, Delay>(&self, driver: &mut MCP3424<I2c, BusError, Delay, OneShotMode>, configuration: &Configuration, max_age_us: u64) -> Result<uom::si::f32::ElectricPotential, Error<BusError>>
+    where
+        I2c: embedded_hal_async::i2c::I2c,
+        BusError: embedded_hal_async::i2c::Error,
+        Delay: embedded_hal_async::delay::DelayNs,
+        Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+    {
+        self.do_measure(driver, configuration, max_age_us).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    fn sample(&self, channel: Channel) -> Option<Sample> {
+        self.samples.lock(|samples| samples.borrow()[channel.mask() as usize])
+    }
+
+    fn do_update(&self, channel: Channel, value: f32) {
+        let timestamp = self.clock.now_us();
+        self.samples.lock(|samples| samples.borrow_mut()[channel.mask() as usize] = Some(Sample { value, timestamp }))
+    }
+
+    fn do_latest(&self, channel: Channel, max_age_us: u64) -> Option<f32> {
+        let now = self.clock.now_us();
+        self.sample(channel)
+            .filter(|sample| now.saturating_sub(sample.timestamp) <= max_age_us)
+            .map(|sample| sample.value)
+    }
+
+    async fn do_measure<I2c, BusError, Delay>(&self, driver: &mut MCP3424<I2c, BusError, Delay, OneShotMode>, configuration: &Configuration, max_age_us: u64) -> Result<f32, Error<BusError>>
+    where
+        I2c: embedded_hal_async::i2c::I2c,
+        BusError: embedded_hal_async::i2c::Error,
+        Delay: embedded_hal_async::delay::DelayNs,
+        Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+    {
+        if let Some(value) = self.do_latest(configuration.channel, max_age_us) {
+            return Ok(value)
+        }
+
+        let mut buffer = [0_u8; 4];
+        let cfg = oneshot_cfg(configuration, Cfg::default());
+
+        let value = driver.convert_single(&cfg, configuration.conversion_time_us(), &mut buffer).await?;
+
+        self.do_update(configuration.channel, value);
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::cell::Cell;
+
+    use alloc::vec;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{Channel, Configuration, MCP3424, OneShotMode, ValueCache};
+    use crate::test_util::TestClock;
+
+    #[rstest]
+    async fn A_ValueCache_should_convert_only_if_the_cached_value_is_too_old() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 1, 0b00100000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+        ]);
+
+        let mut driver = MCP3424::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let testee = ValueCache::<NoopRawMutex, _>::new(TestClock(&time));
+
+        let configuration = Configuration::default().with_channel(Channel::Channel2);
+
+        assert_that!(testee.latest(Channel::Channel2, 500), none());
+
+        let first = testee.measure(&mut driver, &configuration, 500).await?;
+        time.set(500);
+        let cached = testee.measure(&mut driver, &configuration, 500).await?;
+        time.set(501);
+        let second = testee.measure(&mut driver, &configuration, 500).await?;
+
+        assert_that!(cached, eq(first));
+        assert_that!(second, not(eq(first)));
+        assert_that!(testee.age_us(Channel::Channel2), some(eq(0)));
+        assert_that!(testee.age_us(Channel::Channel1), none());
+
+        driver.i2c.done();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+/// Source of monotonic timestamps.
+///
+/// Time-based features of the driver, like an idle timeout, rely on a [`Clock`] to determine the
+/// elapsed time. Implement this trait for the timer of the target platform, e.g. by returning
+/// `embassy_time::Instant::now().as_micros()`.
+///
+pub trait Clock {
+    /// Returns the current timestamp in µs.
+    fn now_us(&self) -> u64;
+}
+
+impl <C: Clock> Clock for &C {
+    fn now_us(&self) -> u64 {
+        (*self).now_us()
+    }
+}
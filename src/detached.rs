@@ -0,0 +1,191 @@
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+
+use embedded_hal_async::i2c::SevenBitAddress;
+
+use crate::{mode, Error, MCP3424};
+
+/// Driver for the MCP342[2/3/4] which does not own the bus.
+///
+/// In contrast to [`MCP3424`], a detached driver keeps only the address, the mode and the reference
+/// voltage. To communicate with the device, the bus and the delay are borrowed by
+/// [`Self::attach`] for as long as needed, whereby the returned [`Attached`] driver offers the same
+/// functions as [`MCP3424`] in the respective mode. This way a single task can drive many devices
+/// and other peripherals on one bus without a mutex.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{DetachedMCP3424, Configuration, OneShotMode};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x69, vec![0b10000000]),
+///#     Transaction::read(0x69, vec![0, 2, 0, 0]),
+///# ]);
+///# let mut delay = Delay;
+///#
+/// let mut adc_1 = DetachedMCP3424::new(0x68, OneShotMode::new(&Configuration::default()));
+/// let mut adc_2 = DetachedMCP3424::new(0x69, OneShotMode::new(&Configuration::default()));
+///
+///# async_std::task::block_on(async {
+/// if let Some(mut adc) = adc_1.attach(&mut i2c, &mut delay) {
+///     println!("Value of the first device: {:?}", adc.measure().await);
+/// }
+/// if let Some(mut adc) = adc_2.attach(&mut i2c, &mut delay) {
+///     println!("Value of the second device: {:?}", adc.measure().await);
+/// }
+///# });
+///# i2c.done();
+/// ```
+///
+pub struct DetachedMCP3424<Mode> {
+    address: SevenBitAddress,
+    mode: Option<Mode>,
+    reference: Option<i64>,
+}
+
+impl <Mode: mode::Mode> DetachedMCP3424<Mode> {
+
+    pub fn new(address: SevenBitAddress, mode: Mode) -> Self {
+        Self {
+            address,
+            mode: Some(mode),
+            reference: None,
+        }
+    }
+
+    /// Returns the address of the device.
+    pub fn address(&self) -> SevenBitAddress {
+        self.address
+    }
+
+    /// Borrows the specified bus and delay and returns a driver to communicate with the device.
+    ///
+    /// Changes of the driver's state, e.g. of the configuration or the reference voltage, are kept
+    /// when the returned driver gets dropped. Returns `None` if a driver returned previously has
+    /// been leaked instead of dropped, since the state of the device has been lost along with it.
+    ///
+    pub fn attach<'a, I2c, BusError, Delay>(&'a mut self, i2c: &'a mut I2c, delay: &'a mut Delay) -> Option<Attached<'a, I2c, BusError, Delay, Mode>>
+    where
+        I2c: embedded_hal_async::i2c::I2c,
+        BusError: embedded_hal_async::i2c::Error,
+        Delay: embedded_hal_async::delay::DelayNs,
+        Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+    {
+        let mode = self.mode.take()?;
+
+        let mut driver = MCP3424::new(i2c, self.address, delay, mode);
+
+        if let Some(reference) = self.reference {
+            driver.reference = reference;
+        }
+
+        Some(Attached {
+            detached: self,
+            driver: ManuallyDrop::new(driver),
+        })
+    }
+}
+
+/// A [`DetachedMCP3424`] attached to a borrowed bus and delay.
+///
+/// Dereferences to a [`MCP3424`] offering the functions of the respective mode. When dropped, the
+/// driver's state is handed back to the [`DetachedMCP3424`].
+///
+pub struct Attached<'a, I2c, BusError, Delay, Mode> {
+    detached: &'a mut DetachedMCP3424<Mode>,
+    driver: ManuallyDrop<MCP3424<&'a mut I2c, BusError, &'a mut Delay, Mode>>,
+}
+
+impl <'a, I2c, BusError, Delay, Mode> Deref for Attached<'a, I2c, BusError, Delay, Mode> {
+    type Target = MCP3424<&'a mut I2c, BusError, &'a mut Delay, Mode>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.driver
+    }
+}
+
+impl <I2c, BusError, Delay, Mode> DerefMut for Attached<'_, I2c, BusError, Delay, Mode> {
+
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.driver
+    }
+}
+
+impl <I2c, BusError, Delay, Mode> Drop for Attached<'_, I2c, BusError, Delay, Mode> {
+
+    fn drop(&mut self) {
+        // SAFETY: The driver is taken exactly once and not accessed afterwards.
+        let driver = unsafe { ManuallyDrop::take(&mut self.driver) };
+        self.detached.reference = Some(driver.reference);
+        self.detached.mode = Some(driver.mode);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{Configuration, ContinuousMode, DetachedMCP3424};
+
+    #[rstest]
+    async fn A_DetachedMCP3424_should_keep_its_state_between_attachments() -> Result<()> {
+
+        let mut i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00010000]),
+            Transaction::read(0x68, vec![0, 1, 0b00010000, 0]),
+            Transaction::read(0x68, vec![0, 2, 0b00010000, 0]),
+        ]);
+
+        let mut delay = NoopDelay;
+
+        let mut testee = DetachedMCP3424::new(0x68, ContinuousMode::new(&Configuration::default()));
+
+        #[cfg(not(feature = "uom"))]
+        {
+            let mut driver = testee.attach(&mut i2c, &mut delay).unwrap();
+            driver.set_reference_voltage(4096.0);
+            assert_that!(driver.measure().await, ok(eq(&2.0)));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(testee.attach(&mut i2c, &mut delay).unwrap().measure().await, ok(eq(&4.0)));
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(testee.attach(&mut i2c, &mut delay).unwrap().measure().await, ok(anything()));
+            assert_that!(testee.attach(&mut i2c, &mut delay).unwrap().measure().await, ok(anything()));
+        }
+
+        i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_DetachedMCP3424_should_not_attach_after_a_driver_has_been_leaked() -> Result<()> {
+
+        let mut i2c = I2c::new(&[]);
+        let mut delay = NoopDelay;
+
+        let mut testee = DetachedMCP3424::new(0x68, ContinuousMode::new(&Configuration::default()));
+
+        core::mem::forget(testee.attach(&mut i2c, &mut delay));
+
+        assert_that!(testee.attach(&mut i2c, &mut delay).is_none(), eq(true));
+
+        i2c.done();
+
+        Ok(())
+    }
+}
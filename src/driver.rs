@@ -1,5 +1,5 @@
 use crate::cfg::Cfg;
-use crate::{mode, Error};
+use crate::{mode, Configuration, ContinuousMode, Error, ModeChangeError, MultiShotMode, OneShotMode};
 use core::marker::PhantomData;
 use core::ops::Not;
 use embedded_hal_async::i2c::SevenBitAddress;
@@ -9,6 +9,21 @@ use embedded_hal_async::i2c::SevenBitAddress;
 /// Depending on the enabled crate features and the specified [`mode::Mode`], there are different measure
 /// functions available.
 ///
+/// # Reference Voltage
+///
+/// The driver assumes the device's nominal reference voltage of 2.048 V to convert output codes. As
+/// the actual reference voltage and the gain error differ from unit to unit, the assumed reference
+/// voltage can be adjusted per driver instance, e.g. to a value determined by a calibration.
+///
+/// The assumed reference voltage is limited to `(0, 4.096]` V, i.e. up to twice the nominal value.
+/// Non-finite or non-positive values are ignored and larger values are clamped.
+///
+/// A gain error is corrected only as part of the reference voltage, e.g. by [`Self::calibrate`],
+/// and therefore applies to all gains alike. Correcting the gain error per [`Gain`] separately is
+/// out of scope.
+///
+/// [`Gain`]: crate::Gain
+///
 /// # See also
 /// [`mode::ContinuousMode`], [`mode::MultiShotMode`], [`mode::OneShotMode`]
 ///
@@ -17,6 +32,7 @@ pub struct MCP3424<I2c, BusError, Delay, Mode> {
     pub(crate) address: u8,
     pub(crate) delay: Delay,
     pub(crate) mode: Mode,
+    pub(crate) reference: i64,
     _phantom: PhantomData<BusError>
 }
 
@@ -28,8 +44,14 @@ where
     Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>,
     Mode: mode::Mode
 {
-    const REFERENCE_VOLTAGE: i64 = 2_048_000_000;
-    const REFERENCE_VOLTAGE_X2: i64 = Self::REFERENCE_VOLTAGE * 2;
+    /// The device's nominal reference voltage in nV.
+    pub(crate) const REFERENCE_VOLTAGE: i64 = 2_048_000_000;
+
+    /// The maximum assumed reference voltage in nV.
+    const MAX_REFERENCE_VOLTAGE: i64 = 2 * Self::REFERENCE_VOLTAGE;
+
+    /// The number of times the device gets polled for new data after the conversion time elapsed.
+    pub(crate) const POLL_ATTEMPTS: u32 = 10;
 
     pub fn new(i2c: I2c, address: SevenBitAddress, delay: Delay, mode: Mode) -> Self {
         Self {
@@ -37,10 +59,114 @@ where
             address,
             delay,
             mode,
+            reference: Self::REFERENCE_VOLTAGE,
             _phantom: PhantomData,
         }
     }
 
+    /// Sets the reference voltage assumed to convert output codes.
+    #[cfg(not(feature = "uom"))]
+    pub fn with_reference_voltage(mut self, millivolts: f32) -> Self {
+        self.set_reference_voltage(millivolts);
+        self
+    }
+
+    /// Sets the reference voltage assumed to convert output codes.
+    #[cfg(feature = "uom")]
+    pub fn with_reference_voltage(mut self, voltage: uom::si::f32::ElectricPotential) -> Self {
+        self.set_reference_voltage(voltage);
+        self
+    }
+
+    /// Sets the reference voltage assumed to convert output codes.
+    ///
+    /// Non-finite or non-positive values are ignored, values above 4.096 V are clamped.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub fn set_reference_voltage(&mut self, millivolts: f32) {
+        self.set_reference(millivolts as f64 * 1_000_000.0)
+    }
+
+    /// Sets the reference voltage assumed to convert output codes.
+    ///
+    /// Non-finite or non-positive values are ignored, values above 4.096 V are clamped.
+    ///
+    #[cfg(feature = "uom")]
+    pub fn set_reference_voltage(&mut self, voltage: uom::si::f32::ElectricPotential) {
+        self.set_reference(voltage.get::<uom::si::electric_potential::millivolt>() as f64 * 1_000_000.0)
+    }
+
+    /// Returns the reference voltage assumed to convert output codes.
+    #[cfg(not(feature = "uom"))]
+    pub fn reference_voltage(&self) -> f32 {
+        (self.reference as f64 / 1_000_000.0) as f32
+    }
+
+    /// Returns the reference voltage assumed to convert output codes.
+    #[cfg(feature = "uom")]
+    pub fn reference_voltage(&self) -> uom::si::f32::ElectricPotential {
+        uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>((self.reference as f64 / 1_000_000.0) as f32)
+    }
+
+    /// Adjusts the assumed reference voltage, so that a value measured with the current reference
+    /// voltage matches the expected value, e.g. the known voltage of a calibrated source.
+    ///
+    /// Measured values which are zero or have a different sign than the expected value are ignored.
+    /// The adjusted reference voltage is limited like in [`Self::set_reference_voltage`].
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub fn calibrate(&mut self, measured: f32, expected: f32) {
+        self.do_calibrate(measured, expected)
+    }
+
+    /// Adjusts the assumed reference voltage, so that a value measured with the current reference
+    /// voltage matches the expected value, e.g. the known voltage of a calibrated source.
+    ///
+    /// Measured values which are zero or have a different sign than the expected value are ignored.
+    /// The adjusted reference voltage is limited like in [`Self::set_reference_voltage`].
+    ///
+    #[cfg(feature = "uom")]
+    pub fn calibrate(&mut self, measured: uom::si::f32::ElectricPotential, expected: uom::si::f32::ElectricPotential) {
+        use uom::si::electric_potential::millivolt;
+        self.do_calibrate(measured.get::<millivolt>(), expected.get::<millivolt>())
+    }
+
+    fn do_calibrate(&mut self, measured: f32, expected: f32) {
+        if let Some(reference) = Self::calibrated_reference(self.reference, measured, expected) {
+            self.reference = reference;
+        }
+    }
+
+    /// Sets the assumed reference voltage in nV, see [`Self::limit_reference`].
+    fn set_reference(&mut self, nanovolts: f64) {
+        if let Some(reference) = Self::limit_reference(nanovolts) {
+            self.reference = reference;
+        }
+    }
+
+    /// Returns the specified reference voltage in nV, clamping values which could overflow the
+    /// conversion, or `None` if the value is non-finite or non-positive.
+    pub(crate) fn limit_reference(nanovolts: f64) -> Option<i64> {
+        if nanovolts.is_finite() && nanovolts >= 1.0 {
+            Some((nanovolts as i64).min(Self::MAX_REFERENCE_VOLTAGE))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the specified reference voltage in nV adjusted, so that the measured value matches
+    /// the expected value, or `None` if the values do not allow an adjustment.
+    pub(crate) fn calibrated_reference(reference: i64, measured: f32, expected: f32) -> Option<i64> {
+        let factor = expected as f64 / measured as f64;
+        if factor.is_finite() && factor > 0.0 {
+            Self::limit_reference(reference as f64 * factor)
+        }
+        else {
+            None
+        }
+    }
+
     pub(crate) async fn read(&mut self, read: &mut [u8]) -> Result<(), I2c::Error> {
         self.i2c.read(self.address, read).await
     }
@@ -49,24 +175,91 @@ where
         self.i2c.write(self.address, write).await
     }
 
-    pub(crate) fn convert(buffer: &[u8; 4]) -> Result<f32, Error<BusError>> {
+    /// Triggers a single conversion using the specified configuration and awaits the result.
+    pub(crate) async fn convert_single(&mut self, cfg: &Cfg, delay: u32, buffer: &mut [u8; 4]) -> Result<f32, Error<BusError>> {
+
+        self.write(&[cfg.as_byte()]).await?;
+
+        self.delay.delay_us(delay).await;
+
+        self.read(buffer).await?;
+
+        Self::convert(buffer, self.reference)
+    }
+
+    /// Awaits the next conversion of the specified configuration by polling the device's RDY bit.
+    ///
+    /// After the specified delay elapsed, the device gets polled in intervals of a tenth of the
+    /// delay. Data which is not new or belongs to another channel is skipped. If there is no new data
+    /// within twice the delay, an [`Error::NotReady`] will be returned.
+    ///
+    pub(crate) async fn await_conversion(&mut self, cfg: &Cfg, delay: u32, buffer: &mut [u8; 4]) -> Result<f32, Error<BusError>> {
+
+        let interval = (delay / Self::POLL_ATTEMPTS).max(1);
+
+        self.delay.delay_us(delay).await;
+
+        for _ in 0..Self::POLL_ATTEMPTS {
 
-        let cfg = if buffer[3] & 0b1100 == 0b1100 {
+            self.read(buffer).await?;
+
+            let output = Self::output_cfg(buffer);
+
+            if output.ready && output.channel.mask() == cfg.channel.mask() {
+                return Self::convert(buffer, self.reference)
+            }
+
+            self.delay.delay_us(interval).await;
+        }
+
+        Err(Error::NotReady)
+    }
+
+    /// Returns the configuration contained in the specified output buffer.
+    pub(crate) fn output_cfg(buffer: &[u8; 4]) -> Cfg {
+        if buffer[3] & 0b1100 == 0b1100 {
             Cfg::from(buffer[3])
         }
         else {
             Cfg::from(buffer[2])
-        };
+        }
+    }
+
+    pub(crate) fn convert(buffer: &[u8; 4], reference: i64) -> Result<f32, Error<BusError>> {
+
+        let cfg = Self::output_cfg(buffer);
 
         if cfg.ready.not() {
             return Err(Error::NotReady)
         }
 
+        Self::decode(buffer, reference)
+    }
+
+    /// Converts the output code contained in the specified buffer using the specified reference
+    /// voltage in nV, if it is new data of the channel of the specified configuration.
+    ///
+    /// Otherwise, an [`Error::NotReady`] will be returned.
+    ///
+    pub(crate) fn convert_channel(buffer: &[u8; 4], cfg: &Cfg, reference: i64) -> Result<f32, Error<BusError>> {
+
+        if Self::output_cfg(buffer).channel.mask() != cfg.channel.mask() {
+            return Err(Error::NotReady)
+        }
+
+        Self::convert(buffer, reference)
+    }
+
+    /// Converts the output code contained in the specified buffer regardless of the RDY bit.
+    pub(crate) fn decode(buffer: &[u8; 4], reference: i64) -> Result<f32, Error<BusError>> {
+
+        let cfg = Self::output_cfg(buffer);
+
         let value = {
             let mut value = 0_u32;
-            for i in 0..cfg.resolution.bytes() {
+            for byte in buffer.iter().take(cfg.resolution.bytes()) {
                 value <<= 8;
-                value |= buffer[i] as u32
+                value |= *byte as u32
             }
             if value & cfg.resolution.sign_bit() != 0 {
                 value |= cfg.resolution.sign_extend()
@@ -78,29 +271,120 @@ where
         let max = cfg.resolution.max();
 
         if value > min && value < max {
-            Ok((value as i64 * Self::REFERENCE_VOLTAGE_X2 / (1 << cfg.resolution.bits())) as f32 / (1_000_000 * cfg.gain.multiplier()) as f32)
+            Ok((value as i64 * reference * 2 / (1 << cfg.resolution.bits())) as f32 / (1_000_000 * cfg.gain.multiplier()) as f32)
         }
         else {
             Err(Error::IllegalValue { value, min, max })
         }
     }
 
+    /// Releases the bus and the delay without changing the device's state. Use [`Self::shutdown`] to
+    /// put the device into standby beforehand.
     pub fn into_inner(self) -> (I2c, Delay) {
         (self.i2c, self.delay)
     }
+
+    /// Puts the device into standby and releases the bus and the delay.
+    ///
+    /// In contrast to [`Self::into_inner`] this function guarantees that the device does not keep
+    /// converting, e.g. in [`ContinuousMode`]. If the device cannot be put into standby, the
+    /// unchanged driver is handed back.
+    ///
+    pub async fn shutdown(mut self) -> Result<(I2c, Delay), ModeChangeError<BusError, Self>> {
+        match self.standby().await {
+            Ok(_) => Ok(self.into_inner()),
+            Err(error) => Err(ModeChangeError { error, driver: self }),
+        }
+    }
+
+    /// Instructs the device to stop converting and to enter the low current standby mode.
+    ///
+    /// This is useful in [`ContinuousMode`] where the device keeps converting, drawing about 135 µA,
+    /// until it gets instructed otherwise. The next call to a measure function wakes the device up
+    /// again. In all other modes the device enters standby after each conversion automatically.
+    ///
+    pub async fn standby(&mut self) -> Result<(), Error<BusError>> {
+        self.enter_standby().await?;
+        self.mode.on_standby();
+        Ok(())
+    }
+
+    /// Converts the driver into [`OneShotMode`] and puts the device into standby.
+    pub async fn into_oneshot(self, configuration: &Configuration) -> Result<MCP3424<I2c, BusError, Delay, OneShotMode>, ModeChangeError<BusError, Self>> {
+        self.into_standby_mode(OneShotMode::new(configuration)).await
+    }
+
+    /// Converts the driver into [`MultiShotMode`] and puts the device into standby.
+    pub async fn into_multishot<const N: usize>(self, configurations: &[Configuration; N]) -> Result<MCP3424<I2c, BusError, Delay, MultiShotMode<N>>, ModeChangeError<BusError, Self>> {
+        self.into_standby_mode(MultiShotMode::new(configurations)).await
+    }
+
+    /// Converts the driver into [`ContinuousMode`], instructs the device to start converting and
+    /// awaits the first conversion.
+    pub async fn into_continuous(mut self, configuration: &Configuration) -> Result<MCP3424<I2c, BusError, Delay, ContinuousMode>, ModeChangeError<BusError, Self>> {
+        let mut mode = ContinuousMode::new(configuration);
+        match self.start_continuous(&mode.cfg(), mode.delay()).await {
+            Ok(_) => {
+                mode.set_initialized();
+                Ok(self.with_mode(mode))
+            }
+            Err(error) => Err(ModeChangeError { error, driver: self }),
+        }
+    }
+
+    async fn into_standby_mode<M: mode::Mode>(mut self, mode: M) -> Result<MCP3424<I2c, BusError, Delay, M>, ModeChangeError<BusError, Self>> {
+        match self.enter_standby().await {
+            Ok(_) => Ok(self.with_mode(mode)),
+            Err(error) => Err(ModeChangeError { error, driver: self }),
+        }
+    }
+
+    /// Instructs the device to convert continuously using the specified configuration and awaits
+    /// the first conversion.
+    pub(crate) async fn start_continuous(&mut self, cfg: &Cfg, delay: u32) -> Result<(), Error<BusError>> {
+
+        self.write(&[cfg.as_byte()]).await?;
+
+        self.delay.delay_us(delay).await;
+
+        Ok(())
+    }
+
+    /// Instructs the device to stop converting and to enter the low current standby mode.
+    pub(crate) async fn enter_standby(&mut self) -> Result<(), Error<BusError>> {
+        let cfg = Cfg {
+            ready: true,
+            mode: crate::cfg::Mode::OneShot,
+            ..Cfg::default()
+        };
+        self.write(&[cfg.as_byte()]).await?;
+        Ok(())
+    }
+
+    fn with_mode<M: mode::Mode>(self, mode: M) -> MCP3424<I2c, BusError, Delay, M> {
+        MCP3424 {
+            i2c: self.i2c,
+            address: self.address,
+            delay: self.delay,
+            mode,
+            reference: self.reference,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod tests {
+    use alloc::vec;
     use embedded_hal_async::i2c::ErrorKind;
-    use embedded_hal_mock::eh1::delay::NoopDelay;
-    use embedded_hal_mock::eh1::i2c::Mock as I2c;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
     use googletest::prelude::*;
     use rstest::rstest;
 
     use super::MCP3424;
-    use crate::OneShotMode;
+    use crate::{Channel, Configuration, ContinuousMode, Error, OneShotMode};
 
     type Testee = MCP3424<I2c, ErrorKind, NoopDelay, OneShotMode>;
 
@@ -122,7 +406,7 @@ mod tests {
         #[case] expected: f32
     ) -> Result<()> {
 
-        assert_that!(&Testee::convert(&code), ok(eq(&expected)));
+        assert_that!(&Testee::convert(&code, Testee::REFERENCE_VOLTAGE), ok(eq(&expected)));
 
         Ok(())
     }
@@ -141,7 +425,7 @@ mod tests {
         #[case] value: i32,
     ) -> Result<()> {
 
-        assert_that!(Testee::convert(&code), err(matches_pattern!(crate::Error::IllegalValue { value: eq(&value) })));
+        assert_that!(Testee::convert(&code, Testee::REFERENCE_VOLTAGE), err(matches_pattern!(crate::Error::IllegalValue { value: eq(&value) })));
 
         Ok(())
     }
@@ -151,7 +435,195 @@ mod tests {
 
         let code = [0, 0, 0b10000000, 0];
 
-        assert_that!(Testee::convert(&code), err(anything()));
+        assert_that!(Testee::convert(&code, Testee::REFERENCE_VOLTAGE), err(anything()));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case([0, 1, 0b00000000, 0], 1_024_000_000, 0.5)]
+    #[case([0, 1, 0b00000000, 0], 4_096_000_000, 2.0)]
+    #[case([255, 255, 0b00000100, 0], 1_024_000_000, -0.125)]
+    fn A_MCP3424_should_convert_an_output_code_using_the_specified_reference_voltage(
+        #[case] code: [u8; 4],
+        #[case] reference: i64,
+        #[case] expected: f32
+    ) -> Result<()> {
+
+        assert_that!(&Testee::convert(&code, reference), ok(eq(&expected)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_MCP3424_should_adjust_the_reference_voltage_by_calibration() -> Result<()> {
+
+        let mut testee = Testee::new(I2c::new(&[]), 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        #[cfg(feature = "uom")]
+        {
+            use uom::si::electric_potential::millivolt;
+            use uom::si::f32::ElectricPotential;
+
+            testee.calibrate(ElectricPotential::new::<millivolt>(1000.0), ElectricPotential::new::<millivolt>(1010.0));
+            verify_that!(testee.reference_voltage().get::<millivolt>(), approx_eq(2068.48))?;
+
+            testee.calibrate(ElectricPotential::new::<millivolt>(0.0), ElectricPotential::new::<millivolt>(1010.0));
+            verify_that!(testee.reference_voltage().get::<millivolt>(), approx_eq(2068.48))?;
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            testee.calibrate(1000.0, 1010.0);
+            verify_that!(testee.reference_voltage(), approx_eq(2068.48))?;
+
+            testee.calibrate(0.0, 1010.0);
+            verify_that!(testee.reference_voltage(), approx_eq(2068.48))?;
+        }
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_MCP3424_should_ignore_or_clamp_invalid_reference_voltages() -> Result<()> {
+
+        let mut testee = Testee::new(I2c::new(&[]), 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        #[cfg(feature = "uom")]
+        {
+            use uom::si::electric_potential::millivolt;
+            use uom::si::f32::ElectricPotential;
+
+            for millivolts in [f32::NAN, f32::INFINITY, 0.0, -2048.0] {
+                testee.set_reference_voltage(ElectricPotential::new::<millivolt>(millivolts));
+                verify_that!(testee.reference, eq(Testee::REFERENCE_VOLTAGE))?;
+            }
+
+            testee.set_reference_voltage(ElectricPotential::new::<millivolt>(1.0e12));
+            verify_that!(testee.reference, eq(Testee::MAX_REFERENCE_VOLTAGE))?;
+
+            testee.calibrate(ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(1000.0));
+            verify_that!(testee.reference, eq(Testee::MAX_REFERENCE_VOLTAGE))?;
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            for millivolts in [f32::NAN, f32::INFINITY, 0.0, -2048.0] {
+                testee.set_reference_voltage(millivolts);
+                verify_that!(testee.reference, eq(Testee::REFERENCE_VOLTAGE))?;
+            }
+
+            testee.set_reference_voltage(1.0e12);
+            verify_that!(testee.reference, eq(Testee::MAX_REFERENCE_VOLTAGE))?;
+
+            testee.calibrate(1.0, 1000.0);
+            verify_that!(testee.reference, eq(Testee::MAX_REFERENCE_VOLTAGE))?;
+        }
+
+        verify_that!(&Testee::convert(&[1, 255, 254, 0b00001100], Testee::MAX_REFERENCE_VOLTAGE), ok(anything()))?;
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424_should_put_the_device_into_the_state_of_the_new_mode() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00110000]),
+            Transaction::read(0x68, vec![0, 1, 0b00110000, 0]),
+            Transaction::write(0x68, vec![0b00000000]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 2, 0b00000000, 0]),
+        ]);
+
+        let testee = Testee::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let mut testee = testee.into_continuous(&Configuration::default().with_channel(Channel::Channel2)).await.unwrap();
+
+        assert_that!(testee.measure().await, ok(anything()));
+
+        let mut testee = testee.into_oneshot(&Configuration::default()).await.unwrap();
+
+        assert_that!(testee.measure().await, ok(anything()));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424_should_await_the_first_conversion_when_entering_ContinuousMode() -> Result<()> {
+
+        let configuration = Configuration::default().with_channel(Channel::Channel2);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00110000]),
+            Transaction::read(0x68, vec![0, 1, 0b00110000, 0]),
+        ]);
+
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::async_delay_us(configuration.conversion_time_us()),
+        ]);
+
+        let testee = MCP3424::new(i2c, 0x68, delay, OneShotMode::new(&Configuration::default()));
+
+        let mut testee = testee.into_continuous(&configuration).await.unwrap();
+
+        assert_that!(testee.measure().await, ok(anything()));
+
+        let (mut i2c, mut delay) = testee.into_inner();
+
+        i2c.done();
+        delay.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424_should_put_the_device_into_standby() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00010000]),
+            Transaction::read(0x68, vec![0, 1, 0b00010000, 0]),
+            Transaction::write(0x68, vec![0b00000000]),
+            Transaction::write(0x68, vec![0b00010000]),
+            Transaction::read(0x68, vec![0, 1, 0b00010000, 0]),
+            Transaction::write(0x68, vec![0b00000000]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        assert_that!(testee.measure().await, ok(anything()));
+        assert_that!(testee.standby().await, ok(anything()));
+        assert_that!(testee.measure().await, ok(anything()));
+
+        let (mut i2c, _) = testee.shutdown().await.unwrap();
+
+        i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_MCP3424_should_hand_back_the_driver_if_a_mode_transition_fails() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00010000]).with_error(ErrorKind::Other),
+        ]);
+
+        let testee = Testee::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let Err(error) = testee.into_continuous(&Configuration::default()).await else {
+            panic!("Expected the transition to fail")
+        };
+
+        assert_that!(error.error, matches_pattern!(Error::BusError(anything())));
+
+        error.driver.into_inner().0.done();
 
         Ok(())
     }
@@ -10,6 +10,23 @@ where
     IllegalValue { value: i32, min: i32, max: i32},
     /// Indicates that the device's output buffer does not contain new data.
     NotReady,
+    /// Indicates a reference to a channel slot which does not exist.
+    InvalidIndex { index: usize, len: usize },
+    /// Indicates that the entry at the index of a channel list refers to a channel which is
+    /// already contained in the list.
+    DuplicateChannel { index: usize },
+    /// Indicates a division by zero, e.g. by a virtual channel.
+    DivisionByZero,
+    /// Indicates that the number of elements exceeds the capacity of a buffer.
+    CapacityExceeded { capacity: usize },
+    /// Indicates that the number of elements does not match the expected number.
+    LengthMismatch { expected: usize, actual: usize },
+    /// Indicates that a period in µs is shorter than the time required for the conversions.
+    Overrun { period: u64, required: u64 },
+    /// Indicates an error of a digital pin, e.g. a trigger input.
+    PinError(embedded_hal::digital::ErrorKind),
+    /// Indicates that the sensor connected to the channel is considered disconnected.
+    SensorOpen,
 }
 
 impl <BusError> core::error::Error for Error<BusError>
@@ -29,6 +46,14 @@ where
             Error::BusError(cause) => defmt::write!(f, "A bus error occurred: {}", cause),
             Error::IllegalValue { value, min, max} => defmt::write!(f, "The measured value '{}' exceeds the valid bounds: {} ≤ {} ≤ {}", value, min, value, max),
             Error::NotReady => defmt::write!(f, "No new data available"),
+            Error::InvalidIndex { index, len } => defmt::write!(f, "The index '{}' is out of bounds: {} < {}", index, index, len),
+            Error::DuplicateChannel { index } => defmt::write!(f, "The channel of the entry at index '{}' is already contained in the list", index),
+            Error::DivisionByZero => defmt::write!(f, "Division by zero"),
+            Error::CapacityExceeded { capacity } => defmt::write!(f, "The capacity of {} elements is exceeded", capacity),
+            Error::LengthMismatch { expected, actual } => defmt::write!(f, "Expected {} elements but got {}", expected, actual),
+            Error::Overrun { period, required } => defmt::write!(f, "The period of {} µs is shorter than the required conversion time of {} µs", period, required),
+            Error::PinError(cause) => defmt::write!(f, "A pin error occurred: {}", cause),
+            Error::SensorOpen => defmt::write!(f, "The sensor is disconnected"),
         }
     }
 }
@@ -42,6 +67,14 @@ where
             Error::BusError(cause) => core::write!(f, "A bus error occurred: {}", cause),
             Error::IllegalValue { value, min, max} => core::write!(f, "The measured value '{}' exceeds the valid bounds: {} ≤ {} ≤ {}", value, min, value, max),
             Error::NotReady => core::write!(f, "No new data available"),
+            Error::InvalidIndex { index, len } => core::write!(f, "The index '{}' is out of bounds: {} < {}", index, index, len),
+            Error::DuplicateChannel { index } => core::write!(f, "The channel of the entry at index '{}' is already contained in the list", index),
+            Error::DivisionByZero => core::write!(f, "Division by zero"),
+            Error::CapacityExceeded { capacity } => core::write!(f, "The capacity of {} elements is exceeded", capacity),
+            Error::LengthMismatch { expected, actual } => core::write!(f, "Expected {} elements but got {}", expected, actual),
+            Error::Overrun { period, required } => core::write!(f, "The period of {} µs is shorter than the required conversion time of {} µs", period, required),
+            Error::PinError(cause) => core::write!(f, "A pin error occurred: {}", cause),
+            Error::SensorOpen => core::write!(f, "The sensor is disconnected"),
         }
     }
 }
@@ -54,3 +87,48 @@ where
         Error::BusError(error)
     }
 }
+
+/// Error type returned by a failed mode transition.
+///
+/// In addition to the cause, the error hands back the unchanged driver, so that it does not get
+/// lost.
+///
+pub struct ModeChangeError<BusError, Driver>
+where
+    BusError: embedded_hal_async::i2c::Error
+{
+    /// The cause of the failed transition.
+    pub error: Error<BusError>,
+    /// The driver in its previous mode.
+    pub driver: Driver,
+}
+
+impl <BusError, Driver> core::fmt::Debug for ModeChangeError<BusError, Driver>
+where
+    BusError: embedded_hal_async::i2c::Error
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ModeChangeError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl <BusError, Driver> defmt::Format for ModeChangeError<BusError, Driver>
+where
+    BusError: embedded_hal_async::i2c::Error + defmt::Format
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Failed to change the mode: {}", self.error)
+    }
+}
+
+impl <BusError, Driver> core::fmt::Display for ModeChangeError<BusError, Driver>
+where
+    BusError: embedded_hal_async::i2c::Error + core::fmt::Display
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "Failed to change the mode: {}", self.error)
+    }
+}
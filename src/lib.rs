@@ -4,11 +4,71 @@
 //!
 //! # Modes
 //!
-//! | Mode                                        | Description                                                                                                                       |
-//! | ------------------------------------------- | --------------------------------------------------------------------------------------------------------------------------------- |
-//! | [OneShot](`crate::mode::OneShotMode`)       | Instructs the device to do a single conversion and awaits the result.                                                             |
-//! | [Continuous](`crate::mode::ContinuousMode`) | Instructs the device to do conversions continuously. Every subsequent call will read the last available value only.               |
-//! | [MultiShot](`crate::mode::MultiShotMode`)   | A variation of the [`OneShotMode`]. The measure functions execute a series of one-shot conversions and return all values at once. |
+//! | Mode                                                    | Description                                                                                                                                         |
+//! | ------------------------------------------------------- | --------------------------------------------------------------------------------------------------------------------------------------------------- |
+//! | [OneShot](`crate::mode::OneShotMode`)                   | Instructs the device to do a single conversion and awaits the result.                                                                               |
+//! | [Continuous](`crate::mode::ContinuousMode`)             | Instructs the device to do conversions continuously. Every subsequent call will read the last available value only.                                 |
+//! | [MultiShot](`crate::mode::MultiShotMode`)               | A variation of the [`OneShotMode`]. The measure functions execute a series of one-shot conversions and return all values at once.                   |
+//! | [RuntimeMultiShot](`crate::mode::RuntimeMultiShotMode`) | A variation of the [`MultiShotMode`]. The number of one-shot conversions is chosen at runtime and the values are written into a buffer.             |
+//! | [Ratiometric](`crate::mode::RatiometricMode`)           | A variation of the [`MultiShotMode`]. Every channel is reported as ratio of a reference channel converted right before.                             |
+//! | [Scan](`crate::mode::ScanMode`)                         | A variation of the [`ContinuousMode`]. The device converts continuously while the channel is switched after each conversion.                        |
+//! | [Dynamic](`crate::mode::DynamicMode`)                   | Wraps the [`OneShotMode`], the [`ContinuousMode`] and the [`RuntimeMultiShotMode`] behind one measure function. The mode can be changed at runtime. |
+//! | [Triggered](`crate::mode::TriggeredMode`)               | A variation of the [`OneShotMode`]. Each conversion is started by an edge on an external trigger input.                                             |
+//!
+//! # Mode Transitions
+//!
+//! The driver can be converted into another mode without tearing it down, e.g. by
+//! [`MCP3424::into_continuous`]. The transition carries over the bus, the delay, the address and
+//! the reference voltage, and puts the device into the state required by the new mode.
+//!
+//! # Virtual Channels
+//!
+//! Derived values like the difference or the ratio of two channels can be computed by the driver
+//! as [`VirtualChannel`]s. These are evaluated after each acquisition of the [`MultiShotMode`] and
+//! returned alongside the measured values.
+//!
+//! # Auto-Zero
+//!
+//! An input pair tied together can be used to track the offset drift of the device, e.g. over
+//! temperature. The [`AutoZero`] periodically measures such a channel and subtracts the tracked
+//! offset from the readings of the `measure_zeroed` functions.
+//!
+//! # Peak Hold
+//!
+//! For diagnostics, a [`PeakHold`] tracks the minimum, the maximum and the peak-to-peak value of
+//! every channel with timestamps and sample counts. It is fed by the `measure_tracked` functions
+//! and can be queried and reset between measurements.
+//!
+//! # Open-Circuit Detection
+//!
+//! A disconnected sensor, e.g. a broken thermocouple, shows up as saturated or floating readings.
+//! The [`OpenCircuitDetector`] evaluates the readings of the `measure_checked` functions and reports
+//! such channels as [`SensorStatus::Open`] along with an [`Error::SensorOpen`]. Optionally, the
+//! [`OneShotMode`] checks a channel actively by biasing its input with a pull-up or pull-down pin.
+//!
+//! # Alarms
+//!
+//! Over- and under-voltage as well as fast changes can be monitored by an [`AlarmMonitor`]. It
+//! evaluates a set of [`Alarm`]s with hysteresis and debounce on the values of any mode and reports
+//! raising and clearing alarms to a callback or, with the `stream` feature, as a stream.
+//! Optionally, it drives an output pin while any alarm is raised.
+//!
+//! # Scheduling
+//!
+//! The [`OneShotMode`] and the [`MultiShotMode`] can measure at a fixed rate according to a
+//! [`Schedule`], which uses a [`Clock`] to compensate jitter and to detect overruns. A
+//! [`RateSchedule`] interleaves the slots of the [`MultiShotMode`] at an individual rate per slot.
+//!
+//! # Bus Sharing
+//!
+//! The [`MCP3424`] owns the bus. To share the bus with other devices, either pass a handle like
+//! `SharedI2c` (feature `embassy`), or use a [`DetachedMCP3424`] which
+//! borrows the bus for each access only.
+//!
+//! # Device Arrays
+//!
+//! Several devices on one bus can be treated as a single logical ADC with one channel list by the
+//! [`MCP3424Array`], which converts the same channel on all devices at once.
 //!
 //! # MCP3422 and MCP3423
 //! In contrast to the MCP3424, the MCP3422 and MCP3423 provide only two channels instead of four.
@@ -23,6 +83,7 @@
 //! | Feature   | Default  | Description                                                                                                                    |
 //! | --------- |:--------:| ------------------------------------------------------------------------------------------------------------------------------ |
 //! | defmt     | &#x2717; | When enabled, certain types will provide an implementation for the [`defmt::Format`] trait.                                    |
+//! | embassy   | &#x2717; | When enabled, bus and device can be shared using [`embassy_sync::mutex::Mutex`], see [`SharedI2c`] and [`ChannelArbiter`].     |
 //! | fmt       | &#x2714; | When enabled, certain types will provide an implementation for [`core::fmt::Debug`] and [`core::fmt::Display`] traits.         |
 //! | stream    | &#x2717; | When enabled, the driver offers additional measure functions which return a [`futures::stream::Stream`].                       |
 //! | uom       | &#x2717; | When enabled, all measure functions return the measured value as [`uom::si::f32::ElectricPotential`] instead of a plain `f32`. |
@@ -41,18 +102,55 @@
 #![cfg_attr(not(test), no_std)]
 extern crate alloc;
 
+pub use crate::alarm::{Alarm, AlarmCondition, AlarmEvent, AlarmMonitor, AlarmTransition, NoOutput};
+pub use crate::array::{MCP3424Array, Synchronization};
+pub use crate::autozero::AutoZero;
+#[cfg(feature = "embassy")]
+pub use crate::cache::ValueCache;
 pub use crate::cfg::{Channel, Gain, Resolution};
+pub use crate::clock::Clock;
 
 pub use crate::config::{Configuration, ConversionTime};
+pub use crate::detached::{Attached, DetachedMCP3424};
 pub use crate::driver::MCP3424;
-pub use crate::error::Error;
-pub use crate::mode::{ContinuousMode, Mode, MultiShotMode, OneShotMode};
+pub use crate::error::{Error, ModeChangeError};
+pub use crate::mode::{Burst, ContinuousMode, DynamicMode, Edge, Mode, MultiShotMode, OneShotMode, RatiometricMode, RuntimeMultiShotMode, ScanMode, Triggered, TriggeredMode};
+pub use crate::open_circuit::{OpenCircuitDetector, SensorStatus};
+#[cfg(feature = "embassy")]
+pub use crate::queue::{MeasurementRequest, RequestQueue};
+pub use crate::scheduler::{RateSchedule, Schedule, Scheduled};
+#[cfg(feature = "embassy")]
+pub use crate::shared::SharedI2c;
+#[cfg(feature = "embassy")]
+pub use crate::split::{ChannelArbiter, ChannelHandle};
+pub use crate::statistics::{PeakHold, Statistics};
+pub use crate::virtual_channel::{Operand, Operator, VirtualChannel};
 
+mod alarm;
+mod array;
+mod autozero;
+#[cfg(feature = "embassy")]
+mod cache;
 mod cfg;
+mod clock;
 mod config;
+mod detached;
 mod driver;
 mod error;
 mod mode;
+mod open_circuit;
+#[cfg(feature = "embassy")]
+mod queue;
+mod scheduler;
+#[cfg(feature = "embassy")]
+mod shared;
+#[cfg(feature = "embassy")]
+mod split;
+mod statistics;
+mod virtual_channel;
+
+#[cfg(test)]
+mod test_util;
 
 #[cfg(doc)]
 pub mod doc;
@@ -1,4 +1,4 @@
-use crate::{cfg, Configuration, Error, MCP3424, Mode};
+use crate::{cfg, Clock, Configuration, Error, MCP3424, Mode, OpenCircuitDetector, PeakHold};
 use crate::cfg::Cfg;
 
 /// A mode where the device continuously converts data.
@@ -7,6 +7,11 @@ use crate::cfg::Cfg;
 /// update its output buffer automatically. Therefor each subsequent call to a measure function just
 /// reads the mose resent data.
 ///
+/// While converting continuously the device draws about 135 µA. To save power, the device can be put
+/// into standby explicitly, or automatically after an idle timeout, see
+/// [`MCP3424::standby_if_idle`]. The next call to a measure function starts the continuous
+/// conversion again.
+///
 /// # Example
 ///
 /// ```
@@ -41,6 +46,9 @@ pub struct ContinuousMode {
     cfg: Cfg,
     delay: u32,
     initialized: bool,
+    idle_timeout: Option<u64>,
+    reads: u32,
+    activity: Option<(u32, u64)>,
 }
 
 impl ContinuousMode {
@@ -50,11 +58,93 @@ impl ContinuousMode {
             cfg: cfg(configuration, Cfg::default()),
             delay: configuration.conversion_time_us(),
             initialized: false,
+            idle_timeout: None,
+            reads: 0,
+            activity: None,
         }
     }
+
+    /// Sets the time in µs without measurements after which the device is put into standby by
+    /// [`MCP3424::standby_if_idle`].
+    pub fn with_idle_timeout(mut self, timeout_us: u64) -> Self {
+        self.idle_timeout = Some(timeout_us);
+        self
+    }
+
+    pub(crate) fn cfg(&self) -> Cfg {
+        self.cfg
+    }
+
+    pub(crate) fn delay(&self) -> u32 {
+        self.delay
+    }
+
+    /// Marks the configuration as applied to the device and the first conversion as done.
+    pub(crate) fn set_initialized(&mut self) {
+        self.initialized = true;
+    }
+}
+
+/// Summary of a burst capture.
+///
+/// See [`MCP3424::measure_burst`].
+///
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Burst {
+    /// The number of captured samples.
+    pub captured: usize,
+    /// The number of conversions lost between two captured samples. Only detected when capturing
+    /// with timestamps.
+    pub missed: u32,
+    /// The number of reads which returned an already captured conversion and have been discarded.
+    pub duplicated: u32,
 }
 
-impl Mode for ContinuousMode {}
+impl Mode for ContinuousMode {
+    fn on_standby(&mut self) {
+        self.initialized = false;
+        self.activity = None;
+    }
+
+    fn continuous(&mut self) -> Option<&mut ContinuousMode> {
+        Some(self)
+    }
+}
+
+impl <I2c, BusError, Delay, M> MCP3424<I2c, BusError, Delay, M>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>,
+    M: Mode
+{
+    /// Reads the latest conversion of the driver's [`ContinuousMode`], starting the continuous
+    /// conversion first if required. The read gets counted as activity, see
+    /// [`MCP3424::standby_if_idle`].
+    pub(crate) async fn read_continuous(&mut self, buffer: &mut [u8; 4]) -> Result<(), Error<BusError>> {
+        self.initialize().await?;
+        self.read(buffer).await?;
+        if let Some(mode) = self.mode.continuous() {
+            mode.reads = mode.reads.wrapping_add(1);
+        }
+        Ok(())
+    }
+
+    /// Starts the continuous conversion and awaits the first conversion, if not done already.
+    async fn initialize(&mut self) -> Result<(), Error<BusError>> {
+        if let Some(mode) = self.mode.continuous().filter(|mode| !mode.initialized) {
+            let (cfg, delay) = (mode.cfg, mode.delay);
+            self.start_continuous(&cfg, delay).await?;
+            if let Some(mode) = self.mode.continuous() {
+                mode.initialized = true;
+            }
+        }
+        Ok(())
+    }
+}
 
 impl <I2c, BusError, Delay> MCP3424<I2c, BusError, Delay, ContinuousMode>
 where
@@ -71,6 +161,37 @@ where
         Ok(())
     }
 
+    /// Puts the device into standby, if the idle timeout elapsed since the last measurement.
+    ///
+    /// Call this function periodically, e.g. from the acquisition loop. Measurements are tracked
+    /// between successive calls, so that the idle time is measured from the first call which
+    /// observes no new measurement. Returns `true` if the device has been put into standby.
+    ///
+    pub async fn standby_if_idle<C: Clock>(&mut self, clock: &C) -> Result<bool, Error<BusError>> {
+
+        let Some(timeout) = self.mode.idle_timeout else {
+            return Ok(false)
+        };
+
+        if !self.mode.initialized {
+            return Ok(false)
+        }
+
+        let now = clock.now_us();
+
+        match self.mode.activity {
+            Some((reads, since)) if reads == self.mode.reads => {
+                if now.saturating_sub(since) >= timeout {
+                    self.standby().await?;
+                    return Ok(true)
+                }
+            }
+            _ => self.mode.activity = Some((self.mode.reads, now)),
+        }
+
+        Ok(false)
+    }
+
     #[cfg(not(feature = "uom"))]
     pub async fn measure(&mut self) -> Result<f32, Error<BusError>> {
         self.do_measure().await
@@ -82,6 +203,148 @@ where
             .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
     }
 
+    /// Reads the last converted value evaluated by the specified [`OpenCircuitDetector`].
+    ///
+    /// If the sensor of the configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<f32, Error<BusError>> {
+        self.do_measure_checked(detector).await
+    }
+
+    /// Reads the last converted value evaluated by the specified [`OpenCircuitDetector`].
+    ///
+    /// If the sensor of the configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure_checked(detector).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Reads the last converted value and adds it to the statistics of the specified [`PeakHold`].
+    ///
+    /// Like [`Self::measure`], an [`Error::NotReady`] will be returned if the latest value has been
+    /// read already. In this case, the statistics remain unchanged.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<f32, Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+    }
+
+    /// Reads the last converted value and adds it to the statistics of the specified [`PeakHold`].
+    ///
+    /// Like [`Self::measure`], an [`Error::NotReady`] will be returned if the latest value has been
+    /// read already. In this case, the statistics remain unchanged.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Returns the next value converted by the device.
+    ///
+    /// In contrast to [`Self::measure`], which fails with an [`Error::NotReady`] if the latest value
+    /// has been read already, this variant waits for the next conversion by polling the device. If
+    /// there is no new data within twice the conversion time, an [`Error::NotReady`] will be
+    /// returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_fresh(&mut self) -> Result<f32, Error<BusError>> {
+        self.do_measure_fresh().await
+    }
+
+    /// Returns the next value converted by the device.
+    ///
+    /// In contrast to [`Self::measure`], which fails with an [`Error::NotReady`] if the latest value
+    /// has been read already, this variant waits for the next conversion by polling the device. If
+    /// there is no new data within twice the conversion time, an [`Error::NotReady`] will be
+    /// returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_fresh(&mut self) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure_fresh().await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Returns the latest value converted by the device together with a flag, which is `true` if
+    /// the value has been read already.
+    ///
+    /// In contrast to [`Self::measure`] this variant does not fail with an [`Error::NotReady`] if
+    /// there is no new data available.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_latest(&mut self) -> Result<(f32, bool), Error<BusError>> {
+        self.do_measure_latest().await
+    }
+
+    /// Returns the latest value converted by the device together with a flag, which is `true` if
+    /// the value has been read already.
+    ///
+    /// In contrast to [`Self::measure`] this variant does not fail with an [`Error::NotReady`] if
+    /// there is no new data available.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_latest(&mut self) -> Result<(uom::si::f32::ElectricPotential, bool), Error<BusError>> {
+        self.do_measure_latest().await
+            .map(|(value, stale)| (uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value), stale))
+    }
+
+    /// Captures consecutive conversions into the specified buffer as fast as the device allows.
+    ///
+    /// The device gets polled for each new conversion, whereby reads which return an already
+    /// captured conversion are discarded and reported as duplicated. If there is no new data
+    /// within twice the conversion time, an [`Error::NotReady`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_burst(&mut self, values: &mut [f32]) -> Result<Burst, Error<BusError>> {
+        self.do_measure_burst(values.len(), || None, |i, value, _| values[i] = value).await
+    }
+
+    /// Captures consecutive conversions into the specified buffer as fast as the device allows.
+    ///
+    /// The device gets polled for each new conversion, whereby reads which return an already
+    /// captured conversion are discarded and reported as duplicated. If there is no new data
+    /// within twice the conversion time, an [`Error::NotReady`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_burst(&mut self, values: &mut [uom::si::f32::ElectricPotential]) -> Result<Burst, Error<BusError>> {
+        self.do_measure_burst(values.len(), || None, |i, value, _| values[i] = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value)).await
+    }
+
+    /// Captures consecutive conversions into the specified buffers like [`Self::measure_burst`] and
+    /// additionally records the time in µs of each sample.
+    ///
+    /// Based on the timestamps, conversions lost between two samples are reported as missed. If the
+    /// buffers differ in length, an [`Error::LengthMismatch`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_burst_timestamped<C: Clock>(&mut self, clock: &C, values: &mut [f32], timestamps: &mut [u64]) -> Result<Burst, Error<BusError>> {
+        check_lengths(values.len(), timestamps.len())?;
+        self.do_measure_burst(values.len(), || Some(clock.now_us()), |i, value, timestamp| {
+            values[i] = value;
+            timestamps[i] = timestamp.unwrap_or_default();
+        }).await
+    }
+
+    /// Captures consecutive conversions into the specified buffers like [`Self::measure_burst`] and
+    /// additionally records the time in µs of each sample.
+    ///
+    /// Based on the timestamps, conversions lost between two samples are reported as missed. If the
+    /// buffers differ in length, an [`Error::LengthMismatch`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_burst_timestamped<C: Clock>(&mut self, clock: &C, values: &mut [uom::si::f32::ElectricPotential], timestamps: &mut [u64]) -> Result<Burst, Error<BusError>> {
+        check_lengths(values.len(), timestamps.len())?;
+        self.do_measure_burst(values.len(), || Some(clock.now_us()), |i, value, timestamp| {
+            values[i] = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value);
+            timestamps[i] = timestamp.unwrap_or_default();
+        }).await
+    }
+
     /// Returns a stream of measured values.
     ///
     /// This variant of measure function prompts the device to continuously convert data and returns
@@ -108,23 +371,161 @@ where
                     .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)))
     }
 
+    /// Returns a stream of freshly converted values.
+    ///
+    /// In contrast to [`Self::measure_stream`], this stream yields exactly one value per completed
+    /// conversion. Each time the stream gets polled, it waits for the next conversion like
+    /// [`Self::measure_fresh`], so that the values are provided at the device's sample rate.
+    ///
+    #[cfg(all(feature = "stream", not(feature = "uom")))]
+    pub async fn measure_fresh_stream(&mut self) -> Result<impl futures::Stream<Item=Result<f32, Error<BusError>>> + '_, Error<BusError>> {
+        self.do_measure_fresh_stream().await
+    }
+
+    /// Returns a stream of freshly converted values.
+    ///
+    /// In contrast to [`Self::measure_stream`], this stream yields exactly one value per completed
+    /// conversion. Each time the stream gets polled, it waits for the next conversion like
+    /// [`Self::measure_fresh`], so that the values are provided at the device's sample rate.
+    ///
+    #[cfg(all(feature = "stream", feature = "uom"))]
+    pub async fn measure_fresh_stream(&mut self) -> Result<impl futures::Stream<Item=Result<uom::si::f32::ElectricPotential, Error<BusError>>> + '_, Error<BusError>> {
+        use futures::StreamExt;
+        self.do_measure_fresh_stream().await
+            .map(|stream| stream
+                .map(|result| result
+                    .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)))
+    }
+
     async fn do_measure(&mut self) -> Result<f32, Error<BusError>> {
 
         let mut buffer = [0_u8; 4];
 
-        if !self.mode.initialized {
-            self.write(&[self.mode.cfg.as_byte()]).await?;
-            self.delay.delay_us(self.mode.delay).await;
-            self.mode.initialized = true;
-        }
+        self.read_continuous(&mut buffer).await?;
+
+        let value = Self::convert(&buffer, self.reference)?;
+
+        Ok(value)
+    }
+
+    async fn do_measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<f32, Error<BusError>> {
+        let result = self.do_measure().await;
+        detector.evaluate(self.mode.cfg.channel, result)
+    }
+
+    async fn do_measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<f32, Error<BusError>> {
+        let value = self.do_measure().await?;
+        peak_hold.record(self.mode.cfg.channel, value);
+        Ok(value)
+    }
+
+    async fn do_measure_fresh(&mut self) -> Result<f32, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        self.initialize().await?;
+
+        let cfg = self.mode.cfg;
 
         self.read(&mut buffer).await?;
 
-        let value = Self::convert(&buffer)?;
+        let value = if Self::output_cfg(&buffer).ready {
+            Self::convert(&buffer, self.reference)?
+        }
+        else {
+            self.await_conversion(&cfg, self.mode.delay, &mut buffer).await?
+        };
+
+        self.mode.reads = self.mode.reads.wrapping_add(1);
 
         Ok(value)
     }
 
+    async fn do_measure_latest(&mut self) -> Result<(f32, bool), Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        self.read_continuous(&mut buffer).await?;
+
+        let stale = !Self::output_cfg(&buffer).ready;
+        let value = Self::decode(&buffer, self.reference)?;
+
+        Ok((value, stale))
+    }
+
+    #[cfg(feature = "stream")]
+    async fn do_measure_fresh_stream(&mut self) -> Result<impl futures::Stream<Item=Result<f32, Error<BusError>>> + '_, Error<BusError>> {
+
+        self.initialize().await?;
+
+        Ok(futures::stream::unfold(self, |device| async move {
+            let result = device.do_measure_fresh().await;
+            Some((result, device))
+        }))
+    }
+
+    async fn do_measure_burst(&mut self, len: usize, mut now: impl FnMut() -> Option<u64>, mut store: impl FnMut(usize, f32, Option<u64>)) -> Result<Burst, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+        let mut burst = Burst { captured: 0, missed: 0, duplicated: 0 };
+        let mut previous = None;
+
+        let delay = self.mode.delay;
+        let interval = (delay / Self::POLL_ATTEMPTS).max(1);
+
+        if self.mode.initialized {
+            // Clears the RDY bit of data converted before the burst.
+            self.read(&mut buffer).await?;
+            self.delay.delay_us(delay - interval.min(delay)).await;
+        }
+        else {
+            self.initialize().await?;
+        }
+
+        for i in 0..len {
+
+            let mut attempts = 0;
+
+            loop {
+                self.read(&mut buffer).await?;
+
+                if Self::output_cfg(&buffer).ready {
+                    break
+                }
+
+                burst.duplicated += 1;
+                attempts += 1;
+
+                if attempts > 2 * Self::POLL_ATTEMPTS {
+                    return Err(Error::NotReady)
+                }
+
+                self.delay.delay_us(interval).await;
+            }
+
+            let timestamp = now();
+
+            if let (Some(timestamp), Some(previous)) = (timestamp, previous) {
+                let elapsed = timestamp.saturating_sub(previous);
+                let conversions = (elapsed + delay as u64 / 2) / (delay as u64).max(1);
+                burst.missed += u32::try_from(conversions.saturating_sub(1)).unwrap_or(u32::MAX);
+            }
+
+            previous = timestamp;
+
+            store(i, Self::convert(&buffer, self.reference)?, timestamp);
+
+            burst.captured += 1;
+            self.mode.reads = self.mode.reads.wrapping_add(1);
+
+            if i + 1 < len {
+                self.delay.delay_us(delay - interval.min(delay)).await;
+            }
+        }
+
+        Ok(burst)
+    }
+
     #[cfg(feature = "stream")]
     async fn do_measure_stream<'a>(&'a mut self) -> Result<impl futures::Stream<Item=Result<f32, Error<BusError>>> + 'a, Error<BusError>> {
 
@@ -137,12 +538,24 @@ where
         Ok(futures::stream::unfold((self, cfg, buffer), |(device, cfg, mut buffer)| async move {
             let result = device.read(&mut buffer).await
                 .map_err(Error::from)
-                .and_then(|_| Self::convert(&buffer));
+                .and_then(|_| Self::convert(&buffer, device.reference));
             Some((result, (device, cfg, buffer)))
         }))
     }
 }
 
+fn check_lengths<BusError>(expected: usize, actual: usize) -> Result<(), Error<BusError>>
+where
+    BusError: embedded_hal_async::i2c::Error
+{
+    if expected == actual {
+        Ok(())
+    }
+    else {
+        Err(Error::LengthMismatch { expected, actual })
+    }
+}
+
 pub(crate) fn cfg(configuration: &Configuration, mut cfg: Cfg) -> Cfg {
     cfg.set_values_from_configuration(configuration);
     cfg.mode = cfg::Mode::Continuous;
@@ -161,8 +574,11 @@ mod tests {
     #[cfg(feature = "uom")]
     use uom::si::f32::ElectricPotential;
 
-    use crate::{Channel, Configuration, ContinuousMode, Gain, MCP3424, Resolution};
+    use core::cell::Cell;
+
+    use crate::{Burst, Channel, Configuration, ContinuousMode, Error, Gain, MCP3424, OpenCircuitDetector, PeakHold, Resolution, SensorStatus, Statistics};
     use crate::cfg::{Cfg, Mode};
+    use crate::test_util::{StepClock, TestClock};
 
     #[fixture]
     fn expected_cfg() -> Cfg {
@@ -210,4 +626,325 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_track_the_statistics_of_the_channel(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 3, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 9, stale_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let time = Cell::new(10);
+
+        let mut peak_hold = PeakHold::new(TestClock(&time));
+
+        testee.measure_tracked(&mut peak_hold).await?;
+        time.set(20);
+        testee.measure_tracked(&mut peak_hold).await?;
+        time.set(30);
+
+        assert_that!(testee.measure_tracked(&mut peak_hold).await, err(matches_pattern!(crate::Error::NotReady)));
+        assert_that!(peak_hold.statistics(Channel::Channel1), some(matches_pattern!(Statistics {
+            minimum: eq(1.0),
+            minimum_timestamp: eq(20),
+            maximum: eq(3.0),
+            maximum_timestamp: eq(10),
+            count: eq(2),
+            since: eq(10),
+        })));
+        assert_that!(peak_hold.statistics(Channel::Channel2), none());
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_enter_standby_after_the_idle_timeout(expected_cfg: Cfg) -> Result<()> {
+
+        let time = Cell::new(0);
+        let clock = TestClock(&time);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![0b00000000]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default())
+            .with_idle_timeout(1000));
+
+        assert_that!(testee.standby_if_idle(&clock).await, ok(eq(&false)));
+        assert_that!(testee.measure().await, ok(anything()));
+        time.set(500);
+        assert_that!(testee.standby_if_idle(&clock).await, ok(eq(&false)));
+        time.set(1400);
+        assert_that!(testee.standby_if_idle(&clock).await, ok(eq(&false)));
+        time.set(1500);
+        assert_that!(testee.standby_if_idle(&clock).await, ok(eq(&true)));
+        time.set(5000);
+        assert_that!(testee.standby_if_idle(&clock).await, ok(eq(&false)));
+        assert_that!(testee.measure().await, ok(anything()));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_wait_for_a_fresh_value(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(testee.measure_fresh().await, ok(eq(&ElectricPotential::new::<millivolt>(1.0))));
+            assert_that!(testee.measure_fresh().await, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(testee.measure_fresh().await, ok(eq(&1.0)));
+            assert_that!(testee.measure_fresh().await, ok(eq(&2.0)));
+        }
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_flag_stale_values(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(testee.measure_latest().await, ok(eq(&(ElectricPotential::new::<millivolt>(1.0), false))));
+            assert_that!(testee.measure_latest().await, ok(eq(&(ElectricPotential::new::<millivolt>(1.0), true))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(testee.measure_latest().await, ok(eq(&(1.0, false))));
+            assert_that!(testee.measure_latest().await, ok(eq(&(1.0, true))));
+        }
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "stream", not(feature = "uom")))]
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_stream_one_value_per_conversion(expected_cfg: Cfg) -> Result<()> {
+        use futures::StreamExt;
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 3, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let values: alloc::vec::Vec<_> = testee.measure_fresh_stream().await?
+            .take(3)
+            .collect()
+            .await;
+
+        assert_that!(values, elements_are![ok(eq(&1.0)), ok(eq(&2.0)), ok(eq(&3.0))]);
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_capture_a_burst(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 3, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let mut values = [Default::default(); 3];
+
+        let burst = testee.measure_burst(&mut values).await?;
+
+        assert_that!(burst, matches_pattern!(Burst { captured: eq(3), missed: eq(0), duplicated: eq(1) }));
+
+        #[cfg(feature = "uom")]
+        assert_that!(values, eq([ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0), ElectricPotential::new::<millivolt>(3.0)]));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(values, eq([1.0, 2.0, 3.0]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_detect_missed_conversions_of_a_burst(expected_cfg: Cfg) -> Result<()> {
+
+        let conversion_time = Configuration::default().conversion_time_us() as u64;
+        let time = Cell::new(0);
+        let clock = StepClock(&time, 3 * conversion_time as i64);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let mut values = [Default::default(); 2];
+        let mut timestamps = [0; 2];
+
+        let burst = testee.measure_burst_timestamped(&clock, &mut values, &mut timestamps).await?;
+
+        assert_that!(burst, matches_pattern!(Burst { captured: eq(2), missed: eq(2), duplicated: eq(0) }));
+        assert_that!(timestamps, eq([3 * conversion_time, 6 * conversion_time]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_tolerate_a_clock_going_backwards_during_a_burst(expected_cfg: Cfg) -> Result<()> {
+
+        let conversion_time = Configuration::default().conversion_time_us() as u64;
+        let time = Cell::new(10 * conversion_time);
+        let clock = StepClock(&time, -(conversion_time as i64));
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let mut values = [Default::default(); 2];
+        let mut timestamps = [0; 2];
+
+        let burst = testee.measure_burst_timestamped(&clock, &mut values, &mut timestamps).await?;
+
+        assert_that!(burst, matches_pattern!(Burst { captured: eq(2), missed: eq(0), duplicated: eq(0) }));
+        assert_that!(timestamps, eq([9 * conversion_time, 8 * conversion_time]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_reject_burst_buffers_of_different_lengths() -> Result<()> {
+
+        let time = Cell::new(0);
+        let clock = TestClock(&time);
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let mut values = [Default::default(); 2];
+        let mut timestamps = [0; 3];
+
+        assert_that!(testee.measure_burst_timestamped(&clock, &mut values, &mut timestamps).await, err(matches_pattern!(Error::LengthMismatch { expected: eq(&2), actual: eq(&3) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ContinuousMode_a_MCP3424_should_report_a_persistently_saturated_channel_as_open(expected_cfg: Cfg) -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0x07, 0xFF, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0x07, 0xFF, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ContinuousMode::new(&Configuration::default()));
+
+        let mut detector = OpenCircuitDetector::new().with_persistence(2);
+
+        assert_that!(testee.measure_checked(&mut detector).await, err(matches_pattern!(Error::IllegalValue { .. })));
+        assert_that!(testee.measure_checked(&mut detector).await, err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Open));
+
+        assert_that!(testee.measure_checked(&mut detector).await, err(matches_pattern!(Error::SensorOpen)));
+
+        let result = testee.measure_checked(&mut detector).await;
+
+        #[cfg(feature = "uom")]
+        assert_that!(result, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+        #[cfg(not(feature = "uom"))]
+        assert_that!(result, ok(eq(&2.0)));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Connected));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
 }
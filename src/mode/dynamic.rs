@@ -0,0 +1,317 @@
+use crate::{Configuration, ContinuousMode, Error, MCP3424, Mode, OneShotMode, RuntimeMultiShotMode};
+
+/// A mode which is selected at runtime.
+///
+/// The Dynamic mode wraps the [`OneShotMode`], the [`ContinuousMode`] and the
+/// [`RuntimeMultiShotMode`] behind one measure function, which writes the values into a buffer
+/// provided by the caller. The mode can be changed at runtime, e.g. according to persistent
+/// settings, whereby the device gets put into standby when leaving the [`ContinuousMode`].
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Configuration, ContinuousMode, DynamicMode, OneShotMode, Error};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x68, vec![0b00010000]),
+///#     Transaction::read(0x68, vec![0, 2, 0, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, DynamicMode::<4>::from(OneShotMode::new(&Configuration::default())));
+///
+///# let _: Result<(), Error<_>> = async_std::task::block_on(async {
+/// let mut values = [Default::default(); 4];
+///
+/// let count = adc.measure(&mut values).await?;
+/// println!("Measured values: {:?}", &values[..count]);
+///
+/// adc.set_mode(ContinuousMode::new(&Configuration::default())).await?;
+///
+/// let count = adc.measure(&mut values).await?;
+/// println!("Measured values: {:?}", &values[..count]);
+///# Ok(())
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// # See also
+/// [`OneShotMode`], [`ContinuousMode`], [`RuntimeMultiShotMode`]
+///
+/// [`OneShotMode`]: crate::OneShotMode
+/// [`ContinuousMode`]: crate::ContinuousMode
+/// [`RuntimeMultiShotMode`]: crate::RuntimeMultiShotMode
+///
+pub enum DynamicMode<const C: usize> {
+    OneShot(OneShotMode),
+    Continuous(ContinuousMode),
+    MultiShot(RuntimeMultiShotMode<C>),
+}
+
+impl <const C: usize> From<OneShotMode> for DynamicMode<C> {
+    fn from(mode: OneShotMode) -> Self {
+        DynamicMode::OneShot(mode)
+    }
+}
+
+impl <const C: usize> From<ContinuousMode> for DynamicMode<C> {
+    fn from(mode: ContinuousMode) -> Self {
+        DynamicMode::Continuous(mode)
+    }
+}
+
+impl <const C: usize> From<RuntimeMultiShotMode<C>> for DynamicMode<C> {
+    fn from(mode: RuntimeMultiShotMode<C>) -> Self {
+        DynamicMode::MultiShot(mode)
+    }
+}
+
+impl <const C: usize> Mode for DynamicMode<C> {
+    fn on_standby(&mut self) {
+        if let DynamicMode::Continuous(mode) = self {
+            mode.on_standby()
+        }
+    }
+
+    fn continuous(&mut self) -> Option<&mut ContinuousMode> {
+        match self {
+            DynamicMode::Continuous(mode) => Some(mode),
+            _ => None,
+        }
+    }
+}
+
+impl <I2c, BusError, Delay, const C: usize> MCP3424<I2c, BusError, Delay, DynamicMode<C>>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    /// Changes the driver's mode. When leaving the [`ContinuousMode`], the device is put into
+    /// standby immediately.
+    pub async fn set_mode(&mut self, mode: impl Into<DynamicMode<C>>) -> Result<(), Error<BusError>> {
+        if let DynamicMode::Continuous(_) = self.mode {
+            self.enter_standby().await?;
+        }
+        self.mode = mode.into();
+        Ok(())
+    }
+
+    /// Updates the configuration of the current mode.
+    ///
+    /// The [`OneShotMode`] and the [`ContinuousMode`] expect exactly one configuration, otherwise an
+    /// [`Error::LengthMismatch`] will be returned. The [`RuntimeMultiShotMode`] accepts up to `C`
+    /// configurations, otherwise an [`Error::CapacityExceeded`] will be returned. In
+    /// [`ContinuousMode`] the configuration is applied to the device immediately and the first
+    /// conversion is awaited.
+    ///
+    pub async fn configure(&mut self, configurations: &[Configuration]) -> Result<(), Error<BusError>> {
+        match &mut self.mode {
+            DynamicMode::OneShot(mode) => {
+                *mode = OneShotMode::new(single(configurations)?);
+            }
+            DynamicMode::Continuous(_) => {
+                let mut mode = ContinuousMode::new(single(configurations)?);
+                self.start_continuous(&mode.cfg(), mode.delay()).await?;
+                mode.set_initialized();
+                self.mode = DynamicMode::Continuous(mode);
+            }
+            DynamicMode::MultiShot(mode) => {
+                mode.configure(configurations)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Measures according to the current mode and writes the results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small, an
+    /// [`Error::CapacityExceeded`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self, values: &mut [f32]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = value).await
+    }
+
+    /// Measures according to the current mode and writes the results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small, an
+    /// [`Error::CapacityExceeded`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self, values: &mut [uom::si::f32::ElectricPotential]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value)).await
+    }
+
+    fn check_buffer(&self, capacity: usize) -> Result<(), Error<BusError>> {
+        let required = match &self.mode {
+            DynamicMode::OneShot(_) | DynamicMode::Continuous(_) => 1,
+            DynamicMode::MultiShot(mode) => mode.len,
+        };
+        if capacity < required {
+            Err(Error::CapacityExceeded { capacity })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    async fn do_measure(&mut self, mut store: impl FnMut(usize, f32)) -> Result<usize, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        match &mut self.mode {
+            DynamicMode::OneShot(mode) => {
+                let (cfg, delay) = (mode.cfg, mode.delay);
+                store(0, self.convert_single(&cfg, delay, &mut buffer).await?);
+                Ok(1)
+            }
+            DynamicMode::Continuous(_) => {
+                self.read_continuous(&mut buffer).await?;
+                store(0, Self::convert(&buffer, self.reference)?);
+                Ok(1)
+            }
+            DynamicMode::MultiShot(mode) => {
+                let (cfgs, delays, len) = (mode.cfgs, mode.delays, mode.len);
+                for i in 0..len {
+                    store(i, self.convert_single(&cfgs[i], delays[i], &mut buffer).await?);
+                }
+                Ok(len)
+            }
+        }
+    }
+}
+
+fn single<BusError>(configurations: &[Configuration]) -> Result<&Configuration, Error<BusError>>
+where
+    BusError: embedded_hal_async::i2c::Error
+{
+    match configurations {
+        [configuration] => Ok(configuration),
+        _ => Err(Error::LengthMismatch { expected: 1, actual: configurations.len() }),
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Channel, Configuration, ContinuousMode, DynamicMode, Error, MCP3424, OneShotMode, RuntimeMultiShotMode};
+
+    #[rstest]
+    async fn When_in_DynamicMode_a_MCP3424_should_measure_according_to_the_current_mode() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b00010000]),
+            Transaction::read(0x68, vec![0, 2, 0b00010000, 0]),
+            Transaction::read(0x68, vec![0, 3, 0b00010000, 0]),
+            Transaction::write(0x68, vec![0b00000000]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 4, 0b00100000, 0]),
+            Transaction::write(0x68, vec![0b11000000]),
+            Transaction::read(0x68, vec![0, 5, 0b01000000, 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, DynamicMode::<4>::from(OneShotMode::new(&Configuration::default())));
+
+        let mut values = [Default::default(); 4];
+
+        assert_that!(testee.measure(&mut values).await, ok(eq(&1)));
+
+        testee.set_mode(ContinuousMode::new(&Configuration::default())).await.unwrap();
+
+        assert_that!(testee.measure(&mut values[1..]).await, ok(eq(&1)));
+        assert_that!(testee.measure(&mut values[2..]).await, ok(eq(&1)));
+
+        testee.set_mode(RuntimeMultiShotMode::new()).await.unwrap();
+        testee.configure(&[
+            Configuration::default().with_channel(Channel::Channel2),
+            Configuration::default().with_channel(Channel::Channel3),
+        ]).await.unwrap();
+
+        assert_that!(testee.measure(&mut values[3..]).await, err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&1) })));
+
+        let mut multi_values = [Default::default(); 2];
+
+        assert_that!(testee.measure(&mut multi_values).await, ok(eq(&2)));
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(&values[..3], eq(&[ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0), ElectricPotential::new::<millivolt>(3.0)]));
+            assert_that!(multi_values, eq([ElectricPotential::new::<millivolt>(4.0), ElectricPotential::new::<millivolt>(5.0)]));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(&values[..3], eq(&[1.0, 2.0, 3.0]));
+            assert_that!(multi_values, eq([4.0, 5.0]));
+        }
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_DynamicMode_a_MCP3424_should_await_the_first_conversion_when_configuring_ContinuousMode() -> Result<()> {
+
+        let configuration = Configuration::default().with_channel(Channel::Channel2);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b00110000]),
+            Transaction::read(0x68, vec![0, 1, 0b00110000, 0]),
+        ]);
+
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::async_delay_us(configuration.conversion_time_us()),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, delay, DynamicMode::<4>::from(ContinuousMode::new(&Configuration::default())));
+
+        testee.configure(&[configuration]).await.unwrap();
+
+        let mut values = [Default::default(); 1];
+
+        assert_that!(testee.measure(&mut values).await, ok(eq(&1)));
+
+        let (mut i2c, mut delay) = testee.into_inner();
+
+        i2c.done();
+        delay.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_DynamicMode_a_MCP3424_should_reject_multiple_configurations_for_a_single_channel_mode() -> Result<()> {
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, DynamicMode::<4>::from(OneShotMode::new(&Configuration::default())));
+
+        let result = testee.configure(&[Configuration::default(), Configuration::default()]).await;
+
+        assert_that!(result, err(matches_pattern!(Error::LengthMismatch { expected: eq(&1), actual: eq(&2) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+}
@@ -1,9 +1,31 @@
-pub use continuous::ContinuousMode;
+pub use continuous::{Burst, ContinuousMode};
+pub use dynamic::DynamicMode;
 pub use multishot::MultiShotMode;
 pub use oneshot::OneShotMode;
+pub use ratiometric::RatiometricMode;
+pub use runtime_multishot::RuntimeMultiShotMode;
+pub use scan::ScanMode;
+pub use triggered::{Edge, Triggered, TriggeredMode};
+
+pub(crate) use oneshot::cfg as oneshot_cfg;
 
 mod continuous;
+mod dynamic;
 mod multishot;
 mod oneshot;
+mod ratiometric;
+mod runtime_multishot;
+mod scan;
+mod triggered;
+
+pub trait Mode {
+    /// Notifies the mode that the device entered the low current standby mode.
+    #[doc(hidden)]
+    fn on_standby(&mut self) {}
 
-pub trait Mode {}
+    /// Returns the state of the continuous conversion, if the mode converts continuously.
+    #[doc(hidden)]
+    fn continuous(&mut self) -> Option<&mut ContinuousMode> {
+        None
+    }
+}
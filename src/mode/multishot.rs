@@ -1,4 +1,4 @@
-use crate::{Configuration, Error, MCP3424, Mode};
+use crate::{AutoZero, Clock, Configuration, Error, MCP3424, Mode, OpenCircuitDetector, PeakHold, RateSchedule, Schedule, Scheduled, VirtualChannel};
 use crate::cfg::Cfg;
 use crate::mode::oneshot;
 
@@ -64,7 +64,7 @@ pub struct MultiShotMode<const N: usize> {
 impl <const N: usize> MultiShotMode<N> {
 
     pub fn new(configurations: &[Configuration; N]) -> Self {
-        let (cfgs, delays) = cfgs_and_delays(configurations);
+        let (cfgs, delays) = cfgs_and_delays(configurations, oneshot::cfg);
         Self {
             cfgs,
             delays,
@@ -83,12 +83,48 @@ where
 {
     /// Updates the driver's configuration. The configuration is applied to the device lazily on
     /// the next measure call.
-    pub fn configure(&mut self, configurations: &[Configuration]) {
-        let (cfgs, delays) = cfgs_and_delays(configurations);
+    pub fn configure(&mut self, configurations: &[Configuration; N]) {
+        let (cfgs, delays) = cfgs_and_delays(configurations, oneshot::cfg);
         self.mode.cfgs = cfgs;
         self.mode.delays = delays;
     }
 
+    /// Updates the driver's configuration like [`Self::configure`] from a slice.
+    ///
+    /// If the number of configurations does not match `N`, an [`Error::LengthMismatch`] will be
+    /// returned and the driver's configuration remains unchanged.
+    ///
+    pub fn try_configure(&mut self, configurations: &[Configuration]) -> Result<(), Error<BusError>> {
+        let configurations: &[Configuration; N] = configurations.try_into()
+            .map_err(|_| Error::LengthMismatch { expected: N, actual: configurations.len() })?;
+        self.configure(configurations);
+        Ok(())
+    }
+
+    /// Updates the configuration of a single slot. The configuration is applied to the device
+    /// lazily on the next measure call.
+    ///
+    /// If the slot does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    pub fn configure_slot(&mut self, index: usize, configuration: &Configuration) -> Result<(), Error<BusError>> {
+        check_index(index, N)?;
+        self.mode.cfgs[index] = oneshot::cfg(configuration, Cfg::default());
+        self.mode.delays[index] = configuration.conversion_time_us();
+        Ok(())
+    }
+
+    /// Swaps the configurations of two slots and therefore the positions of their values.
+    ///
+    /// If a slot does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    pub fn swap_slots(&mut self, a: usize, b: usize) -> Result<(), Error<BusError>> {
+        check_index(a, N)?;
+        check_index(b, N)?;
+        self.mode.cfgs.swap(a, b);
+        self.mode.delays.swap(a, b);
+        Ok(())
+    }
+
     /// Triggers multiple conversions and awaits all results.
     #[cfg(not(feature = "uom"))]
     pub async fn measure(&mut self) -> Result<[f32; N], Error<BusError>> {
@@ -105,6 +141,143 @@ where
                 .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
     }
 
+    /// Triggers multiple conversions and awaits all results corrected by the offsets tracked by the
+    /// specified [`AutoZero`].
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_zeroed(&mut self, auto_zero: &mut AutoZero) -> Result<[f32; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure_zeroed(&mut buffer, auto_zero).await
+    }
+
+    /// Triggers multiple conversions and awaits all results corrected by the offsets tracked by the
+    /// specified [`AutoZero`].
+    #[cfg(feature = "uom")]
+    pub async fn measure_zeroed(&mut self, auto_zero: &mut AutoZero) -> Result<[uom::si::f32::ElectricPotential; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure_zeroed(&mut buffer, auto_zero).await
+            .map(|values| values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
+    }
+
+    /// Triggers multiple conversions, awaits all results and adds them to the statistics of their
+    /// channels tracked by the specified [`PeakHold`].
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<[f32; N], Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+    }
+
+    /// Triggers multiple conversions, awaits all results and adds them to the statistics of their
+    /// channels tracked by the specified [`PeakHold`].
+    #[cfg(feature = "uom")]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<[uom::si::f32::ElectricPotential; N], Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+            .map(|values| values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
+    }
+
+    /// Triggers multiple conversions and awaits all results evaluated by the specified
+    /// [`OpenCircuitDetector`].
+    ///
+    /// All channels are converted and evaluated, even if the conversion of a channel fails. If the
+    /// conversion of any channel fails, the first error other than [`Error::SensorOpen`] will be
+    /// returned. Otherwise, if the sensor of any configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned. The status of each channel can be queried from the
+    /// detector.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<[f32; N], Error<BusError>> {
+        self.do_measure_checked(detector).await
+    }
+
+    /// Triggers multiple conversions and awaits all results evaluated by the specified
+    /// [`OpenCircuitDetector`].
+    ///
+    /// All channels are converted and evaluated, even if the conversion of a channel fails. If the
+    /// conversion of any channel fails, the first error other than [`Error::SensorOpen`] will be
+    /// returned. Otherwise, if the sensor of any configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned. The status of each channel can be queried from the
+    /// detector.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<[uom::si::f32::ElectricPotential; N], Error<BusError>> {
+        self.do_measure_checked(detector).await
+            .map(|values| values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
+    }
+
+    /// Triggers multiple conversions, awaits all results and evaluates the specified virtual channels.
+    ///
+    /// Returns the measured values along with the values of the virtual channels. If a virtual
+    /// channel refers to a non-existing value, an [`Error::InvalidIndex`] will be returned. If a
+    /// virtual channel divides by zero, an [`Error::DivisionByZero`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_virtual<const M: usize>(&mut self, channels: &[VirtualChannel; M]) -> Result<([f32; N], [f32; M]), Error<BusError>> {
+        self.do_measure_virtual(channels).await
+    }
+
+    /// Triggers multiple conversions, awaits all results and evaluates the specified virtual channels.
+    ///
+    /// Returns the measured values along with the values of the virtual channels. If a virtual
+    /// channel refers to a non-existing value, an [`Error::InvalidIndex`] will be returned. If a
+    /// virtual channel divides by zero, an [`Error::DivisionByZero`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_virtual<const M: usize>(&mut self, channels: &[VirtualChannel; M]) -> Result<([uom::si::f32::ElectricPotential; N], [f32; M]), Error<BusError>> {
+        self.do_measure_virtual(channels).await
+            .map(|(values, virtual_values)| (values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>), virtual_values))
+    }
+
+    /// Waits for the next deadline of the specified schedule, then triggers a series of conversions
+    /// and awaits the results.
+    ///
+    /// If the period of the schedule is shorter than the sum of the conversion times, an
+    /// [`Error::Overrun`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<[f32; N]>, Error<BusError>> {
+        self.do_measure_scheduled(schedule).await
+    }
+
+    /// Waits for the next deadline of the specified schedule, then triggers a series of conversions
+    /// and awaits the results.
+    ///
+    /// If the period of the schedule is shorter than the sum of the conversion times, an
+    /// [`Error::Overrun`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<[uom::si::f32::ElectricPotential; N]>, Error<BusError>> {
+        self.do_measure_scheduled(schedule).await
+            .map(|sample| sample.map(|values| values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)))
+    }
+
+    /// Waits for the slot with the earliest deadline of the specified schedule, then triggers a
+    /// single conversion of that slot and returns the slot's index together with the result.
+    ///
+    /// If the conversions of all slots cannot be completed at the requested rates, an
+    /// [`Error::Overrun`] will be returned, indicating the shortest period and the average time
+    /// the conversions require within it.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_next<C: Clock>(&mut self, schedule: &mut RateSchedule<C, N>) -> Result<(usize, Scheduled<f32>), Error<BusError>> {
+        self.do_measure_next(schedule).await
+    }
+
+    /// Waits for the slot with the earliest deadline of the specified schedule, then triggers a
+    /// single conversion of that slot and returns the slot's index together with the result.
+    ///
+    /// If the conversions of all slots cannot be completed at the requested rates, an
+    /// [`Error::Overrun`] will be returned, indicating the shortest period and the average time
+    /// the conversions require within it.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_next<C: Clock>(&mut self, schedule: &mut RateSchedule<C, N>) -> Result<(usize, Scheduled<uom::si::f32::ElectricPotential>), Error<BusError>> {
+        self.do_measure_next(schedule).await
+            .map(|(index, sample)| (index, sample.map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)))
+    }
+
     /// Returns a stream of multiple measured values.
     ///
     /// This variant of measure function triggers a sequence of conversions and awaits their results
@@ -134,16 +307,115 @@ where
 
         let mut values = [0_f32; N];
 
-        for i in 0..N {
-            self.write(&[self.mode.cfgs[i].as_byte()]).await?;
-            self.delay.delay_us(self.mode.delays[i]).await;
-            self.read(buffer).await?;
-            values[i] = Self::convert(buffer)?;
+        for (i, value) in values.iter_mut().enumerate() {
+            let cfg = self.mode.cfgs[i];
+            *value = self.convert_single(&cfg, self.mode.delays[i], buffer).await?;
+        }
+
+        Ok(values)
+    }
+
+    async fn do_measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<[f32; N]>, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        let required = self.mode.delays.iter().map(|delay| *delay as u64).sum();
+        let (timestamp, missed) = self.await_schedule(schedule, required).await?;
+        let value = self.do_measure(&mut buffer).await?;
+        Ok(Scheduled { value, timestamp, missed })
+    }
+
+    async fn do_measure_next<C: Clock>(&mut self, schedule: &mut RateSchedule<C, N>) -> Result<(usize, Scheduled<f32>), Error<BusError>> {
+
+        let (period, required) = schedule.required_us(&self.mode.delays);
+
+        if period < required {
+            return Err(Error::Overrun { period, required })
+        }
+
+        let (index, wait, missed) = schedule.advance();
+
+        self.wait_us(wait).await;
+
+        let timestamp = schedule.now_us();
+        let (cfg, delay) = (self.mode.cfgs[index], self.mode.delays[index]);
+
+        let mut buffer = [0_u8; 4];
+        let value = self.convert_single(&cfg, delay, &mut buffer).await?;
+
+        schedule.record(index, timestamp);
+
+        Ok((index, Scheduled { value, timestamp, missed }))
+    }
+
+    async fn do_measure_zeroed(&mut self, buffer: &mut [u8; 4], auto_zero: &mut AutoZero) -> Result<[f32; N], Error<BusError>> {
+
+        let mut values = [0_f32; N];
+
+        for (i, value) in values.iter_mut().enumerate() {
+            let cfg = self.mode.cfgs[i];
+            *value = self.convert_zeroed(&cfg, self.mode.delays[i], buffer, auto_zero).await?;
+        }
+
+        Ok(values)
+    }
+
+    async fn do_measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<[f32; N], Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+        let mut values = [0_f32; N];
+        let mut open = false;
+        let mut failure = None;
+
+        for (i, value) in values.iter_mut().enumerate() {
+            let cfg = self.mode.cfgs[i];
+            let result = self.convert_single(&cfg, self.mode.delays[i], &mut buffer).await;
+            match detector.evaluate(cfg.channel, result) {
+                Ok(result) => *value = result,
+                Err(Error::SensorOpen) => open = true,
+                Err(error) => {
+                    failure.get_or_insert(error);
+                }
+            }
+        }
+
+        if let Some(error) = failure {
+            return Err(error)
+        }
+
+        if open {
+            return Err(Error::SensorOpen)
+        }
+
+        Ok(values)
+    }
+
+    async fn do_measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<[f32; N], Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        let values = self.do_measure(&mut buffer).await?;
+
+        for (cfg, value) in self.mode.cfgs.iter().zip(values) {
+            peak_hold.record(cfg.channel, value);
         }
 
         Ok(values)
     }
 
+    async fn do_measure_virtual<const M: usize>(&mut self, channels: &[VirtualChannel; M]) -> Result<([f32; N], [f32; M]), Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        let values = self.do_measure(&mut buffer).await?;
+
+        let mut virtual_values = [0_f32; M];
+
+        for (virtual_value, channel) in virtual_values.iter_mut().zip(channels) {
+            *virtual_value = channel.evaluate(&values)?;
+        }
+
+        Ok((values, virtual_values))
+    }
+
     #[cfg(feature = "stream")]
     async fn do_measure_stream<'a>(&'a mut self) -> Result<impl futures::Stream<Item=Result<[f32; N], Error<BusError>>> + 'a, Error<BusError>> {
 
@@ -156,16 +428,30 @@ where
     }
 }
 
-fn cfgs_and_delays<const N: usize>(configurations: &[Configuration]) -> ([Cfg; N], [u32; N]) {
+/// Returns the cfg of each configuration, created by the specified function of the mode, along with
+/// its conversion time.
+pub(crate) fn cfgs_and_delays<const N: usize>(configurations: &[Configuration; N], cfg: fn(&Configuration, Cfg) -> Cfg) -> ([Cfg; N], [u32; N]) {
     let mut cfgs = [Cfg::default(); N];
     let mut delays = [0_u32; N];
     for i in 0..N {
-        cfgs[i] = oneshot::cfg(&configurations[i], cfgs[i]);
+        cfgs[i] = cfg(&configurations[i], cfgs[i]);
         delays[i] = configurations[i].conversion_time_us()
     }
     (cfgs, delays)
 }
 
+pub(crate) fn check_index<BusError>(index: usize, len: usize) -> Result<(), Error<BusError>>
+where
+    BusError: embedded_hal_async::i2c::Error
+{
+    if index < len {
+        Ok(())
+    }
+    else {
+        Err(Error::InvalidIndex { index, len })
+    }
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod tests {
@@ -180,8 +466,9 @@ mod tests {
     #[cfg(feature = "uom")]
     use uom::si::f32::ElectricPotential;
 
-    use crate::{Channel, Configuration, Gain, MCP3424, MultiShotMode, Resolution};
+    use crate::{Channel, Configuration, Error, Gain, MCP3424, MultiShotMode, OpenCircuitDetector, PeakHold, RateSchedule, Resolution, SensorStatus, Statistics, VirtualChannel};
     use crate::cfg::{Cfg, Mode};
+    use crate::test_util::FixedClock;
 
     #[fixture]
     fn expected_cfg() -> Cfg {
@@ -275,4 +562,239 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_evaluate_virtual_channels(expected_cfg: Cfg) -> Result<()> {
+
+        let expected_cfg_2 = Cfg {
+            channel: Channel::Channel2,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 6, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg_2.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..expected_cfg_2 }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2)
+        ]));
+
+        let (_, virtual_values) = testee.measure_virtual(&[
+            VirtualChannel::difference(0, 1),
+            VirtualChannel::quotient(0, 1),
+            VirtualChannel::sum(0, 1).with_scale(0.5),
+        ]).await.unwrap();
+
+        assert_that!(virtual_values, eq([4.0, 3.0, 4.0]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_return_an_error_if_a_virtual_channel_refers_to_a_non_existing_value(expected_cfg: Cfg) -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 6, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+        ]));
+
+        let result = testee.measure_virtual(&[VirtualChannel::difference(0, 1)]).await;
+
+        assert_that!(result, err(matches_pattern!(Error::InvalidIndex { index: eq(&1), len: eq(&1) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_reject_a_configuration_of_another_length() -> Result<()> {
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default()
+        ]));
+
+        assert_that!(testee.try_configure(&[Configuration::default()]), err(matches_pattern!(Error::LengthMismatch { expected: eq(&2), actual: eq(&1) })));
+        assert_that!(testee.configure_slot(2, &Configuration::default()), err(matches_pattern!(Error::InvalidIndex { index: eq(&2), len: eq(&2) })));
+        assert_that!(testee.swap_slots(0, 2), err(matches_pattern!(Error::InvalidIndex { index: eq(&2), len: eq(&2) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_apply_slot_configurations(expected_cfg: Cfg) -> Result<()> {
+
+        let expected_cfg_1 = Cfg {
+            channel: Channel::Channel3,
+            ..expected_cfg
+        };
+
+        let expected_cfg_2 = Cfg {
+            channel: Channel::Channel2,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg_1.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, Cfg { ready: true, ..expected_cfg_1 }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg_2.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..expected_cfg_2 }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default().with_channel(Channel::Channel2),
+            Configuration::default()
+        ]));
+
+        testee.configure_slot(1, &Configuration::default().with_channel(Channel::Channel3)).unwrap();
+        testee.swap_slots(0, 1).unwrap();
+
+        let result = testee.measure().await;
+
+        #[cfg(feature = "uom")]
+        assert_that!(&result, ok(eq(&[ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0)])));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(&result, ok(eq(&[1.0, 2.0])));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_interleave_slots_according_to_a_rate_schedule() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 3, 0b00000000, 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]));
+
+        let mut schedule = RateSchedule::new(FixedClock(0), [10_000, 30_000]);
+
+        let mut slots = [0_usize; 3];
+
+        for slot in slots.iter_mut() {
+            *slot = testee.measure_next(&mut schedule).await?.0;
+        }
+
+        assert_that!(slots, eq([0, 1, 0]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_reject_a_rate_schedule_exceeding_the_conversion_times() -> Result<()> {
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]));
+
+        let mut schedule = RateSchedule::new(FixedClock(0), [10_000, 1_000]);
+
+        assert_that!(testee.measure_next(&mut schedule).await, err(matches_pattern!(Error::Overrun { period: eq(&1_000), required: gt(&1_000) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_track_the_statistics_of_each_channel() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 3, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 0, 0b00100000, 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]));
+
+        let mut peak_hold = PeakHold::new(FixedClock(0));
+
+        testee.measure_tracked(&mut peak_hold).await?;
+        testee.measure_tracked(&mut peak_hold).await?;
+
+        assert_that!(peak_hold.statistics(Channel::Channel1), some(matches_pattern!(Statistics {
+            minimum: eq(1.0),
+            maximum: eq(3.0),
+            count: eq(2),
+        })));
+        assert_that!(peak_hold.statistics(Channel::Channel2), some(matches_pattern!(Statistics {
+            minimum: eq(0.0),
+            maximum: eq(2.0),
+            count: eq(2),
+        })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_MultiShotMode_a_MCP3424_should_evaluate_every_channel_before_reporting_an_error() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0x07, 0xFF, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0x07, 0xFF, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, MultiShotMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]));
+
+        let mut detector = OpenCircuitDetector::new().with_persistence(2);
+
+        assert_that!(testee.measure_checked(&mut detector).await, err(matches_pattern!(Error::IllegalValue { .. })));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Unknown));
+        assert_that!(detector.status(Channel::Channel2), eq(SensorStatus::Connected));
+
+        assert_that!(testee.measure_checked(&mut detector).await, err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Open));
+        assert_that!(detector.status(Channel::Channel2), eq(SensorStatus::Connected));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
 }
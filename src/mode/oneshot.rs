@@ -1,4 +1,6 @@
-use crate::{cfg, Configuration, Error, MCP3424, Mode};
+use embedded_hal::digital::Error as _;
+
+use crate::{cfg, AutoZero, Clock, Configuration, Error, MCP3424, Mode, OpenCircuitDetector, PeakHold, Schedule, Scheduled, SensorStatus};
 use crate::cfg::Cfg;
 use crate::mode::oneshot;
 
@@ -38,8 +40,8 @@ use crate::mode::oneshot;
 /// [`ContinuousMode`]: crate::ContinuousMode
 ///
 pub struct OneShotMode {
-    cfg: Cfg,
-    delay: u32,
+    pub(crate) cfg: Cfg,
+    pub(crate) delay: u32,
 }
 
 impl OneShotMode {
@@ -83,6 +85,111 @@ where
             .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
     }
 
+    /// Triggers a single conversion and awaits the result corrected by the offset tracked by the
+    /// specified [`AutoZero`].
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_zeroed(&mut self, auto_zero: &mut AutoZero) -> Result<f32, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure_zeroed(&mut buffer, auto_zero).await
+    }
+
+    /// Triggers a single conversion and awaits the result corrected by the offset tracked by the
+    /// specified [`AutoZero`].
+    #[cfg(feature = "uom")]
+    pub async fn measure_zeroed(&mut self, auto_zero: &mut AutoZero) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure_zeroed(&mut buffer, auto_zero).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Triggers a single conversion, awaits the result and adds it to the statistics of the
+    /// specified [`PeakHold`].
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<f32, Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+    }
+
+    /// Triggers a single conversion, awaits the result and adds it to the statistics of the
+    /// specified [`PeakHold`].
+    #[cfg(feature = "uom")]
+    pub async fn measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure_tracked(peak_hold).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Triggers a single conversion and awaits the result evaluated by the specified
+    /// [`OpenCircuitDetector`].
+    ///
+    /// If the sensor of the configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<f32, Error<BusError>> {
+        self.do_measure_checked(detector).await
+    }
+
+    /// Triggers a single conversion and awaits the result evaluated by the specified
+    /// [`OpenCircuitDetector`].
+    ///
+    /// If the sensor of the configured channel is considered disconnected, an
+    /// [`Error::SensorOpen`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure_checked(detector).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    /// Checks the sensor of the configured channel by biasing the input via the specified pin, e.g.
+    /// driving a pull-up or pull-down resistor.
+    ///
+    /// The pin is set high for a single conversion and set low afterwards. A connected sensor holds
+    /// the input at its level, whereas an open input is pulled into saturation. The resulting
+    /// status is stored in the specified [`OpenCircuitDetector`] and returned. If setting the level
+    /// of the pin fails, an [`Error::PinError`] will be returned.
+    ///
+    pub async fn check_open<Pin: embedded_hal::digital::OutputPin>(&mut self, detector: &mut OpenCircuitDetector, bias: &mut Pin) -> Result<SensorStatus, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        bias.set_high().map_err(|error| Error::PinError(error.kind()))?;
+        let result = self.do_measure(&mut buffer).await;
+        bias.set_low().map_err(|error| Error::PinError(error.kind()))?;
+
+        let status = match result {
+            Ok(_) => SensorStatus::Connected,
+            Err(Error::IllegalValue { .. }) => SensorStatus::Open,
+            Err(error) => return Err(error),
+        };
+
+        detector.set_status(self.mode.cfg.channel, status);
+
+        Ok(status)
+    }
+
+    /// Waits for the next deadline of the specified schedule, then triggers a single conversion and
+    /// awaits the result.
+    ///
+    /// If the period of the schedule is shorter than the conversion time, an [`Error::Overrun`]
+    /// will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<f32>, Error<BusError>> {
+        self.do_measure_scheduled(schedule).await
+    }
+
+    /// Waits for the next deadline of the specified schedule, then triggers a single conversion and
+    /// awaits the result.
+    ///
+    /// If the period of the schedule is shorter than the conversion time, an [`Error::Overrun`]
+    /// will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<uom::si::f32::ElectricPotential>, Error<BusError>> {
+        self.do_measure_scheduled(schedule).await
+            .map(|sample| sample.map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
+    }
+
     /// Returns a stream of measured values.
     ///
     /// This variant of measure function triggers a single conversion and awaits the result each
@@ -108,15 +215,32 @@ where
     }
 
     async fn do_measure(&mut self, buffer: &mut [u8; 4]) -> Result<f32, Error<BusError>> {
+        let cfg = self.mode.cfg;
+        self.convert_single(&cfg, self.mode.delay, buffer).await
+    }
 
-        self.write(&[self.mode.cfg.as_byte()]).await?;
-
-        self.delay.delay_us(self.mode.delay).await;
+    async fn do_measure_scheduled<C: Clock>(&mut self, schedule: &mut Schedule<C>) -> Result<Scheduled<f32>, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        let (timestamp, missed) = self.await_schedule(schedule, self.mode.delay as u64).await?;
+        let value = self.do_measure(&mut buffer).await?;
+        Ok(Scheduled { value, timestamp, missed })
+    }
 
-        self.read(buffer).await?;
+    async fn do_measure_zeroed(&mut self, buffer: &mut [u8; 4], auto_zero: &mut AutoZero) -> Result<f32, Error<BusError>> {
+        let cfg = self.mode.cfg;
+        self.convert_zeroed(&cfg, self.mode.delay, buffer, auto_zero).await
+    }
 
-        let value = Self::convert(buffer)?;
+    async fn do_measure_checked(&mut self, detector: &mut OpenCircuitDetector) -> Result<f32, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        let result = self.do_measure(&mut buffer).await;
+        detector.evaluate(self.mode.cfg.channel, result)
+    }
 
+    async fn do_measure_tracked<C: Clock>(&mut self, peak_hold: &mut PeakHold<C>) -> Result<f32, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        let value = self.do_measure(&mut buffer).await?;
+        peak_hold.record(self.mode.cfg.channel, value);
         Ok(value)
     }
 
@@ -151,8 +275,11 @@ mod tests {
     #[cfg(feature = "uom")]
     use uom::si::f32::ElectricPotential;
 
-    use crate::{Channel, Configuration, Gain, MCP3424, OneShotMode, Resolution};
+    use core::cell::Cell;
+
+    use crate::{AutoZero, Channel, Configuration, Gain, MCP3424, OneShotMode, OpenCircuitDetector, PeakHold, Resolution, Schedule, SensorStatus, Statistics};
     use crate::cfg::{Cfg, Mode};
+    use crate::test_util::TestClock;
 
     #[fixture]
     fn expected_cfg() -> Cfg {
@@ -212,4 +339,182 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    async fn When_in_OneShotMode_a_MCP3424_should_subtract_the_offset_of_the_zero_channel(expected_cfg: Cfg) -> Result<()> {
+
+        let zero_cfg = Cfg {
+            channel: Channel::Channel4,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![zero_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, Cfg { ready: true, ..zero_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 3, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 4, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+        ]);
+
+        let mut auto_zero = AutoZero::new(Channel::Channel4);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(&testee.measure_zeroed(&mut auto_zero).await, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+            assert_that!(&testee.measure_zeroed(&mut auto_zero).await, ok(eq(&ElectricPotential::new::<millivolt>(3.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(&testee.measure_zeroed(&mut auto_zero).await, ok(eq(&2.0)));
+            assert_that!(&testee.measure_zeroed(&mut auto_zero).await, ok(eq(&3.0)));
+        }
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_OneShotMode_a_MCP3424_should_measure_according_to_a_schedule(expected_cfg: Cfg) -> Result<()> {
+
+        let returned_cfg = Cfg {
+            ready: true,
+            ..expected_cfg
+        };
+
+        let time = Cell::new(0);
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, returned_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, returned_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let mut schedule = Schedule::new(TestClock(&time), 10_000);
+
+        let first = testee.measure_scheduled(&mut schedule).await?;
+        time.set(25_000);
+        let second = testee.measure_scheduled(&mut schedule).await?;
+
+        assert_that!((first.timestamp, first.missed), eq((0, 0)));
+        assert_that!((second.timestamp, second.missed), eq((25_000, 1)));
+        assert_that!(schedule.overruns(), eq(1));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_OneShotMode_a_MCP3424_should_reject_a_schedule_faster_than_the_conversion() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, OneShotMode::new(&Configuration::default()
+            .with_resolution(Resolution::EighteenBits)));
+
+        let mut schedule = Schedule::new(TestClock(&time), 10_000);
+
+        assert_that!(testee.measure_scheduled(&mut schedule).await, err(matches_pattern!(crate::Error::Overrun { period: eq(&10_000), required: gt(&10_000) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_OneShotMode_a_MCP3424_should_track_the_statistics_of_the_channel(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let returned_cfg = Cfg {
+            ready: true,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 3, returned_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, returned_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 9, stale_cfg.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let time = Cell::new(10);
+
+        let mut peak_hold = PeakHold::new(TestClock(&time));
+
+        testee.measure_tracked(&mut peak_hold).await?;
+        time.set(20);
+        testee.measure_tracked(&mut peak_hold).await?;
+        time.set(30);
+
+        assert_that!(testee.measure_tracked(&mut peak_hold).await, err(matches_pattern!(crate::Error::NotReady)));
+        assert_that!(peak_hold.statistics(Channel::Channel1), some(matches_pattern!(Statistics {
+            minimum: eq(1.0),
+            minimum_timestamp: eq(20),
+            maximum: eq(3.0),
+            maximum_timestamp: eq(10),
+            count: eq(2),
+            since: eq(10),
+        })));
+        assert_that!(peak_hold.statistics(Channel::Channel2), none());
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_OneShotMode_a_MCP3424_should_detect_an_open_input_pulled_into_saturation(expected_cfg: Cfg) -> Result<()> {
+        use embedded_hal_mock::eh1::digital::{Mock as Pin, State as PinState, Transaction as PinTransaction};
+
+        let returned_cfg = Cfg {
+            ready: true,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0x07, 0xFF, returned_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, returned_cfg.as_byte(), 0]),
+        ]);
+
+        let mut bias = Pin::new(&[
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, OneShotMode::new(&Configuration::default()));
+
+        let mut detector = OpenCircuitDetector::new();
+
+        assert_that!(testee.check_open(&mut detector, &mut bias).await, ok(eq(&SensorStatus::Open)));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Open));
+
+        assert_that!(testee.check_open(&mut detector, &mut bias).await, ok(eq(&SensorStatus::Connected)));
+        assert_that!(detector.status(Channel::Channel1), eq(SensorStatus::Connected));
+
+        testee.i2c.done();
+        bias.done();
+
+        Ok(())
+    }
+
 }
@@ -0,0 +1,238 @@
+use crate::{Configuration, Error, MCP3424, Mode};
+use crate::cfg::Cfg;
+use crate::mode::{multishot, oneshot};
+
+/// A mode which reports values as ratios of a reference channel.
+///
+/// The Ratiometric mode is a variation of the [`MultiShotMode`] where one channel measures the
+/// excitation or supply voltage of the connected sensors. The measure functions trigger a one-shot
+/// conversion of the reference channel immediately followed by a one-shot conversion of a channel,
+/// for every configured channel, and return the ratio of both values. This way a drift of the
+/// supply voltage cancels out, instead of showing up in the readings.
+///
+/// <div class="warning">
+/// <b>Important</b>
+///
+/// Even though both conversions are executed back-to-back, they <b>do not</b> represent values
+/// from ADC converted at the same time!
+/// </div>
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Configuration, RatiometricMode, Channel};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b11100000]),
+///#     Transaction::read(0x68, vec![0, 4, 0, 0]),
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, RatiometricMode::new(
+///     &Configuration::default()
+///         .with_channel(Channel::Channel4),
+///     &[
+///         Configuration::default()
+///             .with_channel(Channel::Channel1),
+///     ]
+/// ));
+///
+///# async_std::task::block_on(async {
+/// match adc.measure().await {
+///     Ok(ratios) => println!("Ratio of channel 1: {:?}", ratios[0]),
+///     Err(_) => println!("Failed to measure")
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// # See also
+/// [`OneShotMode`], [`MultiShotMode`]
+///
+/// [`OneShotMode`]: crate::OneShotMode
+/// [`MultiShotMode`]: crate::MultiShotMode
+///
+pub struct RatiometricMode<const N: usize> {
+    reference_cfg: Cfg,
+    reference_delay: u32,
+    cfgs: [Cfg; N],
+    delays: [u32; N],
+}
+
+impl <const N: usize> RatiometricMode<N> {
+
+    pub fn new(reference: &Configuration, configurations: &[Configuration; N]) -> Self {
+        let (cfgs, delays) = multishot::cfgs_and_delays(configurations, oneshot::cfg);
+        Self {
+            reference_cfg: oneshot::cfg(reference, Cfg::default()),
+            reference_delay: reference.conversion_time_us(),
+            cfgs,
+            delays,
+        }
+    }
+}
+
+impl <const N: usize> Mode for RatiometricMode<N> {}
+
+impl <I2c, BusError, Delay, const N: usize> MCP3424<I2c, BusError, Delay, RatiometricMode<N>>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    /// Updates the driver's configuration. The configuration is applied to the device lazily on
+    /// the next measure call.
+    pub fn configure(&mut self, reference: &Configuration, configurations: &[Configuration; N]) {
+        self.mode = RatiometricMode::new(reference, configurations);
+    }
+
+    /// Triggers pairs of reference and channel conversions and awaits all ratios.
+    ///
+    /// If the reference channel measures zero, an [`Error::DivisionByZero`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self) -> Result<[f32; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure(&mut buffer).await
+    }
+
+    /// Triggers pairs of reference and channel conversions and awaits all ratios.
+    ///
+    /// If the reference channel measures zero, an [`Error::DivisionByZero`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self) -> Result<[uom::si::f32::Ratio; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure(&mut buffer).await
+            .map(|values| values
+                .map(uom::si::f32::Ratio::new::<uom::si::ratio::ratio>))
+    }
+
+    async fn do_measure(&mut self, buffer: &mut [u8; 4]) -> Result<[f32; N], Error<BusError>> {
+
+        let mut ratios = [0_f32; N];
+
+        let reference_cfg = self.mode.reference_cfg;
+
+        for (i, ratio) in ratios.iter_mut().enumerate() {
+            let cfg = self.mode.cfgs[i];
+            let reference = self.convert_single(&reference_cfg, self.mode.reference_delay, buffer).await?;
+            let value = self.convert_single(&cfg, self.mode.delays[i], buffer).await?;
+            if reference == 0.0 {
+                return Err(Error::DivisionByZero)
+            }
+            *ratio = value / reference;
+        }
+
+        Ok(ratios)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::{fixture, rstest};
+
+    #[cfg(feature = "uom")]
+    use uom::si::f32::Ratio;
+    #[cfg(feature = "uom")]
+    use uom::si::ratio::ratio;
+
+    use crate::{Channel, Configuration, Error, Gain, MCP3424, RatiometricMode, Resolution};
+    use crate::cfg::{Cfg, Mode};
+
+    #[fixture]
+    fn expected_cfg() -> Cfg {
+        Cfg {
+            ready: false,
+            channel: Channel::Channel1,
+            resolution: Resolution::TwelveBits,
+            mode: Mode::OneShot,
+            gain: Gain::X1
+        }
+    }
+
+    #[rstest]
+    async fn When_in_RatiometricMode_a_MCP3424_should_measure_the_reference_before_each_channel(expected_cfg: Cfg) -> Result<()> {
+
+        let reference_cfg = Cfg {
+            channel: Channel::Channel4,
+            ..expected_cfg
+        };
+
+        let channel_cfg_1 = expected_cfg;
+
+        let channel_cfg_2 = Cfg {
+            channel: Channel::Channel2,
+            gain: Gain::X2,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![reference_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 8, Cfg { ready: true, ..reference_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![channel_cfg_1.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..channel_cfg_1 }.as_byte(), 0]),
+            Transaction::write(0x68, vec![reference_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 4, Cfg { ready: true, ..reference_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![channel_cfg_2.as_byte()]),
+            Transaction::read(0x68, vec![0, 4, Cfg { ready: true, ..channel_cfg_2 }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, RatiometricMode::new(
+            &Configuration::default().with_channel(Channel::Channel4),
+            &[
+                Configuration::default(),
+                Configuration::default().with_channel(Channel::Channel2).with_gain(Gain::X2),
+            ]
+        ));
+
+        let result = testee.measure().await;
+
+        #[cfg(feature = "uom")]
+        assert_that!(&result, ok(eq(&[Ratio::new::<ratio>(0.25), Ratio::new::<ratio>(0.5)])));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(&result, ok(eq(&[0.25, 0.5])));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_RatiometricMode_a_MCP3424_should_reject_a_reference_of_zero(expected_cfg: Cfg) -> Result<()> {
+
+        let reference_cfg = Cfg {
+            channel: Channel::Channel4,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![reference_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 0, Cfg { ready: true, ..reference_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, RatiometricMode::new(
+            &Configuration::default().with_channel(Channel::Channel4),
+            &[Configuration::default()]
+        ));
+
+        assert_that!(testee.measure().await, err(matches_pattern!(Error::DivisionByZero)));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+use crate::{Configuration, Error, MCP3424, Mode};
+use crate::cfg::Cfg;
+use crate::mode::{multishot, oneshot};
+
+/// A mode which triggers a sequence of one-shot conversions, whose length is chosen at runtime.
+///
+/// The Runtime-Multi-Shot mode is a variation of the [`MultiShotMode`] where the number of
+/// configurations is not fixed at compile time. The driver holds up to `C` configurations, e.g. 4
+/// to cover all channels of a device or more to sample channels repeatedly, and the measure
+/// functions write the values into a buffer provided by the caller.
+///
+/// <div class="warning">
+/// <b>Important</b>
+///
+/// This mode <b>is not</b> a device feature and is completely implemented within software by triggering
+/// multiple one-shot conversions sequentially. Therefor the returned results <b>do not</b> represent
+/// values from ADC converted at the same time!
+/// </div>
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Configuration, RuntimeMultiShotMode, Channel, Error};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b11000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x68, vec![0b11100000]),
+///#     Transaction::read(0x68, vec![0, 2, 0, 0]),
+///# ]);
+///#
+/// let configurations = [
+///     Configuration::default()
+///         .with_channel(Channel::Channel3),
+///     Configuration::default()
+///         .with_channel(Channel::Channel4)
+/// ];
+///
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, RuntimeMultiShotMode::<4>::new());
+///
+///# let _: Result<(), Error<_>> = async_std::task::block_on(async {
+/// adc.configure(&configurations)?;
+///
+/// let mut values = [Default::default(); 4];
+/// let count = adc.measure(&mut values).await?;
+///
+/// for value in &values[..count] {
+///     println!("Measured value: {:?}", value);
+/// }
+///# Ok(())
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// # See also
+/// [`OneShotMode`], [`MultiShotMode`]
+///
+/// [`OneShotMode`]: crate::OneShotMode
+/// [`MultiShotMode`]: crate::MultiShotMode
+///
+pub struct RuntimeMultiShotMode<const C: usize> {
+    pub(crate) cfgs: [Cfg; C],
+    pub(crate) delays: [u32; C],
+    pub(crate) len: usize,
+}
+
+impl <const C: usize> RuntimeMultiShotMode<C> {
+
+    pub fn new() -> Self {
+        Self {
+            cfgs: [Cfg::default(); C],
+            delays: [0_u32; C],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of configurations.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of configurations.
+    pub fn capacity(&self) -> usize {
+        C
+    }
+
+    pub(crate) fn configure<BusError>(&mut self, configurations: &[Configuration]) -> Result<(), Error<BusError>>
+    where
+        BusError: embedded_hal_async::i2c::Error
+    {
+        if configurations.len() > C {
+            return Err(Error::CapacityExceeded { capacity: C })
+        }
+
+        for (i, configuration) in configurations.iter().enumerate() {
+            self.cfgs[i] = oneshot::cfg(configuration, Cfg::default());
+            self.delays[i] = configuration.conversion_time_us();
+        }
+
+        self.len = configurations.len();
+
+        Ok(())
+    }
+}
+
+impl <const C: usize> Default for RuntimeMultiShotMode<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <const C: usize> Mode for RuntimeMultiShotMode<C> {}
+
+impl <I2c, BusError, Delay, const C: usize> MCP3424<I2c, BusError, Delay, RuntimeMultiShotMode<C>>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    /// Updates the driver's configuration. The configuration is applied to the device lazily on
+    /// the next measure call.
+    ///
+    /// If the number of configurations exceeds the capacity, an [`Error::CapacityExceeded`] will be
+    /// returned and the driver's configuration remains unchanged.
+    ///
+    pub fn configure(&mut self, configurations: &[Configuration]) -> Result<(), Error<BusError>> {
+        self.mode.configure(configurations)
+    }
+
+    /// Updates the configuration of a single slot. The configuration is applied to the device
+    /// lazily on the next measure call.
+    ///
+    /// If the slot does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    pub fn configure_slot(&mut self, index: usize, configuration: &Configuration) -> Result<(), Error<BusError>> {
+        multishot::check_index(index, self.mode.len)?;
+        self.mode.cfgs[index] = oneshot::cfg(configuration, Cfg::default());
+        self.mode.delays[index] = configuration.conversion_time_us();
+        Ok(())
+    }
+
+    /// Appends a slot with the specified configuration.
+    ///
+    /// If the capacity is exhausted, an [`Error::CapacityExceeded`] will be returned.
+    ///
+    pub fn push_slot(&mut self, configuration: &Configuration) -> Result<(), Error<BusError>> {
+        self.insert_slot(self.mode.len, configuration)
+    }
+
+    /// Inserts a slot with the specified configuration at the specified position, shifting all
+    /// subsequent slots.
+    ///
+    /// If the position is greater than the number of slots, an [`Error::InvalidIndex`] will be
+    /// returned. If the capacity is exhausted, an [`Error::CapacityExceeded`] will be returned.
+    ///
+    pub fn insert_slot(&mut self, index: usize, configuration: &Configuration) -> Result<(), Error<BusError>> {
+        multishot::check_index(index, self.mode.len + 1)?;
+        if self.mode.len == C {
+            return Err(Error::CapacityExceeded { capacity: C })
+        }
+        self.mode.cfgs[index..=self.mode.len].rotate_right(1);
+        self.mode.delays[index..=self.mode.len].rotate_right(1);
+        self.mode.len += 1;
+        self.configure_slot(index, configuration)
+    }
+
+    /// Removes the slot at the specified position, shifting all subsequent slots.
+    ///
+    /// If the slot does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    pub fn remove_slot(&mut self, index: usize) -> Result<(), Error<BusError>> {
+        multishot::check_index(index, self.mode.len)?;
+        self.mode.cfgs[index..self.mode.len].rotate_left(1);
+        self.mode.delays[index..self.mode.len].rotate_left(1);
+        self.mode.len -= 1;
+        Ok(())
+    }
+
+    /// Swaps the configurations of two slots and therefore the positions of their values.
+    ///
+    /// If a slot does not exist, an [`Error::InvalidIndex`] will be returned.
+    ///
+    pub fn swap_slots(&mut self, a: usize, b: usize) -> Result<(), Error<BusError>> {
+        multishot::check_index(a, self.mode.len)?;
+        multishot::check_index(b, self.mode.len)?;
+        self.mode.cfgs.swap(a, b);
+        self.mode.delays.swap(a, b);
+        Ok(())
+    }
+
+    /// Triggers multiple conversions and writes all results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small to hold a value for every
+    /// configuration, an [`Error::CapacityExceeded`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self, values: &mut [f32]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = value).await
+    }
+
+    /// Triggers multiple conversions and writes all results into the specified buffer.
+    ///
+    /// Returns the number of written values. If the buffer is too small to hold a value for every
+    /// configuration, an [`Error::CapacityExceeded`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self, values: &mut [uom::si::f32::ElectricPotential]) -> Result<usize, Error<BusError>> {
+        self.check_buffer(values.len())?;
+        self.do_measure(|i, value| values[i] = uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(value)).await
+    }
+
+    fn check_buffer(&self, capacity: usize) -> Result<(), Error<BusError>> {
+        if capacity < self.mode.len {
+            Err(Error::CapacityExceeded { capacity })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    async fn do_measure(&mut self, mut store: impl FnMut(usize, f32)) -> Result<usize, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        for i in 0..self.mode.len {
+            let cfg = self.mode.cfgs[i];
+            let value = self.convert_single(&cfg, self.mode.delays[i], &mut buffer).await?;
+            store(i, value);
+        }
+
+        Ok(self.mode.len)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::{fixture, rstest};
+
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Channel, Configuration, Error, Gain, MCP3424, Resolution, RuntimeMultiShotMode};
+    use crate::cfg::{Cfg, Mode};
+
+    #[fixture]
+    fn expected_cfg() -> Cfg {
+        Cfg {
+            ready: false,
+            channel: Channel::Channel1,
+            resolution: Resolution::TwelveBits,
+            mode: Mode::OneShot,
+            gain: Gain::X1
+        }
+    }
+
+    #[rstest]
+    async fn When_in_RuntimeMultiShotMode_a_MCP3424_should_trigger_the_configured_conversions(expected_cfg: Cfg) -> Result<()> {
+
+        let expected_cfg_1 = Cfg {
+            channel: Channel::Channel2,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg_1.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, Cfg { ready: true, ..expected_cfg_1 }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg_1.as_byte()]),
+            Transaction::read(0x68, vec![0, 3, Cfg { ready: true, ..expected_cfg_1 }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, RuntimeMultiShotMode::<4>::new());
+
+        testee.configure(&[
+            Configuration::default().with_channel(Channel::Channel2),
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]).unwrap();
+
+        let mut values = [Default::default(); 4];
+
+        assert_that!(testee.measure(&mut values).await, ok(eq(&3)));
+
+        #[cfg(feature = "uom")]
+        assert_that!(&values[..3], eq(&[ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0), ElectricPotential::new::<millivolt>(3.0)]));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(&values[..3], eq(&[1.0, 2.0, 3.0]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_RuntimeMultiShotMode_a_MCP3424_should_return_an_error_if_a_capacity_is_exceeded() -> Result<()> {
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, RuntimeMultiShotMode::<2>::new());
+
+        assert_that!(testee.configure(&[Configuration::default(), Configuration::default(), Configuration::default()]), err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&2) })));
+        assert_that!(testee.mode.len(), eq(0));
+
+        testee.configure(&[Configuration::default(), Configuration::default()]).unwrap();
+
+        let mut values = [Default::default(); 1];
+
+        assert_that!(testee.measure(&mut values).await, err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&1) })));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_RuntimeMultiShotMode_a_MCP3424_should_insert_remove_and_swap_slots(expected_cfg: Cfg) -> Result<()> {
+
+        let expected_cfg_3 = Cfg {
+            channel: Channel::Channel3,
+            ..expected_cfg
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg_3.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, Cfg { ready: true, ..expected_cfg_3 }.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 2, Cfg { ready: true, ..expected_cfg }.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, RuntimeMultiShotMode::<3>::new());
+
+        testee.push_slot(&Configuration::default()).unwrap();
+        testee.push_slot(&Configuration::default().with_channel(Channel::Channel2)).unwrap();
+        testee.insert_slot(1, &Configuration::default().with_channel(Channel::Channel3)).unwrap();
+
+        assert_that!(testee.push_slot(&Configuration::default()), err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&3) })));
+        assert_that!(testee.remove_slot(3), err(matches_pattern!(Error::InvalidIndex { index: eq(&3), len: eq(&3) })));
+
+        testee.remove_slot(2).unwrap();
+        testee.swap_slots(0, 1).unwrap();
+
+        let mut values = [Default::default(); 2];
+
+        assert_that!(testee.measure(&mut values).await, ok(eq(&2)));
+
+        #[cfg(feature = "uom")]
+        assert_that!(values, eq([ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0)]));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(values, eq([1.0, 2.0]));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+}
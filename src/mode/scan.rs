@@ -0,0 +1,240 @@
+use crate::{Configuration, Error, MCP3424, Mode};
+use crate::cfg::Cfg;
+use crate::mode::{continuous, multishot};
+
+/// A mode where the device continuously converts data while the driver scans several channels.
+///
+/// In Scan mode, the device keeps converting continuously. The measure functions switch the channel
+/// in a round-robin fashion each time a conversion of the current channel completed and return the
+/// latest values of all channels at once. Conversions which may still reflect the previous channel
+/// are discarded: After switching the channel, the driver skips the data already available and
+/// waits for the configured number of conversions to be discarded before taking a value.
+///
+/// **Default:** One conversion is discarded after switching the channel.
+///
+/// <div class="warning">
+/// <b>Important</b>
+///
+/// The returned results <b>do not</b> represent values from ADC converted at the same time!
+/// </div>
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Configuration, ScanMode, Channel};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b00010000]),
+///#     Transaction::read(0x68, vec![0, 0, 0b10010000, 0]),
+///#     Transaction::read(0x68, vec![0, 1, 0b00010000, 0]),
+///#     Transaction::write(0x68, vec![0b00110000]),
+///#     Transaction::read(0x68, vec![0, 0, 0b10110000, 0]),
+///#     Transaction::read(0x68, vec![0, 2, 0b00110000, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, ScanMode::new(&[
+///     Configuration::default()
+///         .with_channel(Channel::Channel1),
+///     Configuration::default()
+///         .with_channel(Channel::Channel2)
+/// ]).with_discarded_conversions(0));
+///
+///# async_std::task::block_on(async {
+/// match adc.measure().await {
+///     Ok(value) => {
+///         println!("Latest value of channel 1: {:?}", value[0]);
+///         println!("Latest value of channel 2: {:?}", value[1]);
+///     }
+///     Err(_) => println!("Failed to measure")
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// # See also
+/// [`ContinuousMode`], [`MultiShotMode`]
+///
+/// [`ContinuousMode`]: crate::ContinuousMode
+/// [`MultiShotMode`]: crate::MultiShotMode
+///
+pub struct ScanMode<const N: usize> {
+    cfgs: [Cfg; N],
+    delays: [u32; N],
+    discarded: u8,
+}
+
+impl <const N: usize> ScanMode<N> {
+
+    pub fn new(configurations: &[Configuration; N]) -> Self {
+        let (cfgs, delays) = multishot::cfgs_and_delays(configurations, continuous::cfg);
+        Self {
+            cfgs,
+            delays,
+            discarded: 1,
+        }
+    }
+
+    /// Sets the number of conversions discarded after switching the channel.
+    pub fn with_discarded_conversions(mut self, discarded: u8) -> Self {
+        self.discarded = discarded;
+        self
+    }
+}
+
+impl <const N: usize> Mode for ScanMode<N> {}
+
+impl <I2c, BusError, Delay, const N: usize> MCP3424<I2c, BusError, Delay, ScanMode<N>>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    /// Updates the driver's configuration. The configuration is applied to the device on the next
+    /// measure call.
+    pub fn configure(&mut self, configurations: &[Configuration; N]) {
+        let discarded = self.mode.discarded;
+        self.mode = ScanMode::new(configurations)
+            .with_discarded_conversions(discarded);
+    }
+
+    /// Scans all channels once and returns their latest values.
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self) -> Result<[f32; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure(&mut buffer).await
+    }
+
+    /// Scans all channels once and returns their latest values.
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self) -> Result<[uom::si::f32::ElectricPotential; N], Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        self.do_measure(&mut buffer).await
+            .map(|values| values
+                .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>))
+    }
+
+    async fn do_measure(&mut self, buffer: &mut [u8; 4]) -> Result<[f32; N], Error<BusError>> {
+
+        let mut values = [0_f32; N];
+
+        for (i, value) in values.iter_mut().enumerate() {
+
+            let cfg = self.mode.cfgs[i];
+            let delay = self.mode.delays[i];
+
+            self.write(&[cfg.as_byte()]).await?;
+
+            // Clears the RDY bit of data converted before switching the channel.
+            self.read(buffer).await?;
+
+            for _ in 0..self.mode.discarded {
+                self.await_conversion(&cfg, delay, buffer).await?;
+            }
+
+            *value = self.await_conversion(&cfg, delay, buffer).await?;
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::{fixture, rstest};
+
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Channel, Configuration, Gain, MCP3424, Resolution, ScanMode};
+    use crate::cfg::{Cfg, Mode};
+
+    #[fixture]
+    fn expected_cfg() -> Cfg {
+        Cfg {
+            ready: true,
+            channel: Channel::Channel1,
+            resolution: Resolution::TwelveBits,
+            mode: Mode::Continuous,
+            gain: Gain::X1
+        }
+    }
+
+    #[rstest]
+    async fn When_in_ScanMode_a_MCP3424_should_discard_conversions_after_switching_the_channel(expected_cfg: Cfg) -> Result<()> {
+
+        let expected_cfg_2 = Cfg {
+            channel: Channel::Channel2,
+            ..expected_cfg
+        };
+
+        let stale_cfg_2 = Cfg {
+            ready: false,
+            ..expected_cfg_2
+        };
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 9, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 8, expected_cfg.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg.as_byte(), 0]),
+            Transaction::write(0x68, vec![expected_cfg_2.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, expected_cfg_2.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg_2.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 7, expected_cfg_2.as_byte(), 0]),
+            Transaction::read(0x68, vec![0, 2, expected_cfg_2.as_byte(), 0]),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, ScanMode::new(&[
+            Configuration::default(),
+            Configuration::default().with_channel(Channel::Channel2),
+        ]));
+
+        let result = testee.measure().await;
+
+        #[cfg(feature = "uom")]
+        assert_that!(&result, ok(eq(&[ElectricPotential::new::<millivolt>(1.0), ElectricPotential::new::<millivolt>(2.0)])));
+
+        #[cfg(not(feature = "uom"))]
+        assert_that!(&result, ok(eq(&[1.0, 2.0])));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_ScanMode_a_MCP3424_should_return_an_error_if_there_is_no_new_data(expected_cfg: Cfg) -> Result<()> {
+
+        let stale_cfg = Cfg {
+            ready: false,
+            ..expected_cfg
+        };
+
+        let mut transactions = vec![
+            Transaction::write(0x68, vec![expected_cfg.as_byte()]),
+            Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0]),
+        ];
+        transactions.extend((0..10).map(|_| Transaction::read(0x68, vec![0, 1, stale_cfg.as_byte(), 0])));
+
+        let mut testee = MCP3424::new(I2c::new(&transactions), 0x68, NoopDelay, ScanMode::new(&[
+            Configuration::default(),
+        ]).with_discarded_conversions(0));
+
+        assert_that!(testee.measure().await, err(matches_pattern!(crate::Error::NotReady)));
+
+        testee.i2c.done();
+
+        Ok(())
+    }
+}
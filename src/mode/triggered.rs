@@ -0,0 +1,247 @@
+use embedded_hal::digital::Error as _;
+use embedded_hal_async::digital::Wait;
+
+use crate::{Configuration, Error, MCP3424, Mode};
+use crate::cfg::Cfg;
+use crate::mode::oneshot;
+
+/// A mode where conversions are triggered by an external signal.
+///
+/// The Triggered mode is a variation of the [`OneShotMode`] where the measure functions wait for an
+/// edge on a trigger input, e.g. an encoder index or the period of a PWM, before starting a single
+/// conversion. This way the acquisition can be slaved to external timing. The results are tagged
+/// with the number of triggers received so far.
+///
+/// **Default:** Conversions are triggered by a rising edge.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::digital::{Edge as MockEdge, Mock as Pin, Transaction as PinTransaction};
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Configuration, Edge, TriggeredMode};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 2, 0, 0]),
+///# ]);
+///# let pin = Pin::new(&[PinTransaction::wait_for_edge(MockEdge::Falling)]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, TriggeredMode::new(pin, &Configuration::default())
+///     .with_edge(Edge::Falling));
+///
+///# async_std::task::block_on(async {
+/// match adc.measure().await {
+///     Ok(sample) => println!("Measured value {:?} on trigger {}", sample.value, sample.trigger),
+///     Err(_) => println!("Failed to measure")
+/// }
+///# });
+///# let (mut i2c, _, mut pin) = adc.into_parts();
+///# pin.done();
+///# i2c.done();
+/// ```
+///
+/// # See also
+/// [`OneShotMode`]
+///
+/// [`OneShotMode`]: crate::OneShotMode
+///
+pub struct TriggeredMode<Pin> {
+    pin: Pin,
+    cfg: Cfg,
+    delay: u32,
+    edge: Edge,
+    count: u32,
+}
+
+impl <Pin: Wait> TriggeredMode<Pin> {
+
+    pub fn new(pin: Pin, configuration: &Configuration) -> Self {
+        Self {
+            pin,
+            cfg: oneshot::cfg(configuration, Cfg::default()),
+            delay: configuration.conversion_time_us(),
+            edge: Edge::Rising,
+            count: 0,
+        }
+    }
+
+    /// Sets the edge of the trigger input which starts a conversion.
+    pub fn with_edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+}
+
+impl <Pin: Wait> Mode for TriggeredMode<Pin> {}
+
+/// An edge of a trigger input.
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Edge {
+    Rising,
+    Falling,
+    Any,
+}
+
+/// A value measured in response to a trigger.
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Triggered<T> {
+    /// The measured value.
+    pub value: T,
+    /// The number of the trigger which started the conversion, starting at 1.
+    pub trigger: u32,
+}
+
+impl <I2c, BusError, Delay, Pin> MCP3424<I2c, BusError, Delay, TriggeredMode<Pin>>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>,
+    Pin: Wait
+{
+    /// Updates the driver's configuration. The configuration is applied to the device on the next
+    /// trigger.
+    pub fn configure(&mut self, configuration: &Configuration) {
+        self.mode.cfg = oneshot::cfg(configuration, Cfg::default());
+        self.mode.delay = configuration.conversion_time_us();
+    }
+
+    /// Releases the bus, the delay and the trigger input.
+    pub fn into_parts(self) -> (I2c, Delay, Pin) {
+        (self.i2c, self.delay, self.mode.pin)
+    }
+
+    /// Returns the number of triggers received so far.
+    pub fn trigger_count(&self) -> u32 {
+        self.mode.count
+    }
+
+    /// Waits for the next trigger, then triggers a single conversion and awaits the result.
+    ///
+    /// If waiting for the trigger fails, an [`Error::PinError`] will be returned.
+    ///
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self) -> Result<Triggered<f32>, Error<BusError>> {
+        self.do_measure().await
+    }
+
+    /// Waits for the next trigger, then triggers a single conversion and awaits the result.
+    ///
+    /// If waiting for the trigger fails, an [`Error::PinError`] will be returned.
+    ///
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self) -> Result<Triggered<uom::si::f32::ElectricPotential>, Error<BusError>> {
+        self.do_measure().await
+            .map(|sample| Triggered {
+                value: uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>(sample.value),
+                trigger: sample.trigger,
+            })
+    }
+
+    async fn do_measure(&mut self) -> Result<Triggered<f32>, Error<BusError>> {
+
+        let mut buffer = [0_u8; 4];
+
+        match self.mode.edge {
+            Edge::Rising => self.mode.pin.wait_for_rising_edge().await,
+            Edge::Falling => self.mode.pin.wait_for_falling_edge().await,
+            Edge::Any => self.mode.pin.wait_for_any_edge().await,
+        }.map_err(|error| Error::PinError(error.kind()))?;
+
+        self.mode.count = self.mode.count.wrapping_add(1);
+
+        let trigger = self.mode.count;
+        let (cfg, delay) = (self.mode.cfg, self.mode.delay);
+
+        let value = self.convert_single(&cfg, delay, &mut buffer).await?;
+
+        Ok(Triggered { value, trigger })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embedded_hal::digital::ErrorKind;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::digital::{Edge as MockEdge, Mock as Pin, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use embedded_hal_mock::eh1::MockError;
+    use googletest::prelude::*;
+    use rstest::rstest;
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Configuration, Edge, Error, MCP3424, TriggeredMode};
+
+    #[rstest]
+    async fn When_in_TriggeredMode_a_MCP3424_should_convert_on_each_trigger() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 2, 0b00000000, 0]),
+        ]);
+
+        let pin = Pin::new(&[
+            PinTransaction::wait_for_edge(MockEdge::Any),
+            PinTransaction::wait_for_edge(MockEdge::Any),
+        ]);
+
+        let mut testee = MCP3424::new(i2c, 0x68, NoopDelay, TriggeredMode::new(pin, &Configuration::default())
+            .with_edge(Edge::Any));
+
+        let first = testee.measure().await?;
+        let second = testee.measure().await?;
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(first.value, eq(ElectricPotential::new::<millivolt>(1.0)));
+            assert_that!(second.value, eq(ElectricPotential::new::<millivolt>(2.0)));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(first.value, eq(1.0));
+            assert_that!(second.value, eq(2.0));
+        }
+        assert_that!(first.trigger, eq(1));
+        assert_that!(second.trigger, eq(2));
+        assert_that!(testee.trigger_count(), eq(2));
+
+        testee.mode.pin.done();
+        testee.i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn When_in_TriggeredMode_a_MCP3424_should_not_convert_if_the_trigger_fails() -> Result<()> {
+
+        let pin = Pin::new(&[
+            PinTransaction::wait_for_edge(MockEdge::Rising)
+                .with_error(MockError::Io(std::io::ErrorKind::Other)),
+        ]);
+
+        let mut testee = MCP3424::new(I2c::new(&[]), 0x68, NoopDelay, TriggeredMode::new(pin, &Configuration::default()));
+
+        assert_that!(testee.measure().await, err(matches_pattern!(Error::PinError(eq(&ErrorKind::Other)))));
+        assert_that!(testee.trigger_count(), eq(0));
+
+        testee.mode.pin.done();
+        testee.i2c.done();
+
+        Ok(())
+    }
+}
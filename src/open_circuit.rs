@@ -0,0 +1,289 @@
+use crate::{Channel, Error};
+
+/// Detection of disconnected sensors, e.g. a broken thermocouple or a cut wire.
+///
+/// An open input floats and shows up as saturated or as implausibly noisy readings. The
+/// [`OpenCircuitDetector`] evaluates the readings of the `measure_checked` functions per channel and
+/// marks a channel as [`SensorStatus::Open`] if
+///
+/// * the reading saturates, i.e. the conversion fails with an [`Error::IllegalValue`], or
+/// * the reading changes by more than the maximum step in mV between two consecutive samples, or
+/// * the reading jitters, i.e. two consecutive steps of more than the maximum noise in mV change
+///   their direction, as a floating input does even within the maximum step,
+///
+/// for `persistence` consecutive samples. Measurements of an open channel fail with an
+/// [`Error::SensorOpen`] until `persistence` consecutive readings are plausible again. Additionally,
+/// the [`OneShotMode`] can check a channel actively by biasing the input with a pull-up or
+/// pull-down resistor, see `check_open`.
+///
+/// * The persistence specifies the number of consecutive samples required to change the status.
+///   **Default:** `3`
+/// * The maximum step and the maximum noise are disabled by default. Non-finite values are ignored.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Channel, Configuration, Error, OneShotMode, OpenCircuitDetector};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 2, 0, 0]),
+///# ]);
+///#
+/// let mut detector = OpenCircuitDetector::new()
+///     .with_persistence(5)
+///     .with_max_step(100.0)
+///     .with_max_noise(5.0);
+///
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+///# async_std::task::block_on(async {
+/// match adc.measure_checked(&mut detector).await {
+///     Ok(value) => println!("Measured value: {:?}", value),
+///     Err(Error::SensorOpen) => println!("Sensor disconnected"),
+///     Err(_) => println!("Failed to measure")
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// [`OneShotMode`]: crate::OneShotMode
+///
+#[derive(Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OpenCircuitDetector {
+    persistence: u8,
+    max_step: Option<f32>,
+    max_noise: Option<f32>,
+    channels: [ChannelState; 4],
+}
+
+/// The status of the sensor connected to a channel, see [`OpenCircuitDetector`].
+#[derive(Copy, Clone, Default, PartialEq)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SensorStatus {
+    /// No reading has been evaluated yet.
+    #[default]
+    Unknown,
+    Connected,
+    Open,
+}
+
+#[derive(Copy, Clone, Default)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct ChannelState {
+    status: SensorStatus,
+    previous: Option<f32>,
+    step: Option<f32>,
+    count: u8,
+}
+
+impl OpenCircuitDetector {
+
+    pub fn new() -> Self {
+        Self {
+            persistence: 3,
+            max_step: None,
+            max_noise: None,
+            channels: [ChannelState::default(); 4],
+        }
+    }
+
+    pub fn with_persistence(mut self, persistence: u8) -> Self {
+        self.persistence = persistence.max(1);
+        self
+    }
+
+    /// Sets the maximum change in mV between two consecutive samples of a connected sensor.
+    pub fn with_max_step(mut self, max_step: f32) -> Self {
+        if max_step.is_finite() {
+            self.max_step = Some(max_step.max(-max_step));
+        }
+        self
+    }
+
+    /// Sets the maximum noise in mV, i.e. the size above which two consecutive steps of a connected
+    /// sensor do not change their direction.
+    pub fn with_max_noise(mut self, max_noise: f32) -> Self {
+        if max_noise.is_finite() {
+            self.max_noise = Some(max_noise.max(-max_noise));
+        }
+        self
+    }
+
+    /// Returns the status of the sensor connected to the specified channel.
+    pub fn status(&self, channel: Channel) -> SensorStatus {
+        self.channels[channel.mask() as usize].status
+    }
+
+    /// Discards the status of all channels.
+    pub fn reset(&mut self) {
+        self.channels = [ChannelState::default(); 4];
+    }
+
+    /// Evaluates the specified result of a conversion of the specified channel.
+    ///
+    /// Returns an [`Error::SensorOpen`] if the channel is considered open, otherwise the specified
+    /// result. Errors other than [`Error::IllegalValue`] are passed through without evaluation.
+    pub(crate) fn evaluate<BusError>(&mut self, channel: Channel, result: Result<f32, Error<BusError>>) -> Result<f32, Error<BusError>>
+    where
+        BusError: embedded_hal_async::i2c::Error
+    {
+        let state = &mut self.channels[channel.mask() as usize];
+
+        let plausible = match &result {
+            Ok(value) => {
+                let step = state.previous.replace(*value).map(|previous| value - previous);
+                let previous_step = core::mem::replace(&mut state.step, step);
+                let steady = match (step, self.max_step) {
+                    (Some(step), Some(max_step)) => step.max(-step) <= max_step,
+                    _ => true,
+                };
+                let quiet = match (step, previous_step, self.max_noise) {
+                    (Some(step), Some(previous_step), Some(max_noise)) => {
+                        step * previous_step >= 0.0 || step.max(-step) <= max_noise || previous_step.max(-previous_step) <= max_noise
+                    }
+                    _ => true,
+                };
+                steady && quiet
+            }
+            Err(Error::IllegalValue { .. }) => {
+                state.previous = None;
+                state.step = None;
+                false
+            }
+            Err(_) => return result,
+        };
+
+        let open = state.status == SensorStatus::Open;
+
+        if plausible == open {
+            state.count = state.count.saturating_add(1);
+            if state.count >= self.persistence {
+                state.status = if open { SensorStatus::Connected } else { SensorStatus::Open };
+                state.count = 0;
+            }
+        }
+        else {
+            state.count = 0;
+            if plausible {
+                state.status = SensorStatus::Connected;
+            }
+        }
+
+        if state.status == SensorStatus::Open {
+            Err(Error::SensorOpen)
+        }
+        else {
+            result
+        }
+    }
+
+    /// Sets the status of the specified channel according to an active check.
+    pub(crate) fn set_status(&mut self, channel: Channel, status: SensorStatus) {
+        self.channels[channel.mask() as usize] = ChannelState { status, ..ChannelState::default() };
+    }
+}
+
+impl Default for OpenCircuitDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use embedded_hal::i2c::ErrorKind;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{Channel, Error, OpenCircuitDetector, SensorStatus};
+
+    fn saturated() -> core::result::Result<f32, Error<ErrorKind>> {
+        Err(Error::IllegalValue { value: 2047, min: -2048, max: 2047 })
+    }
+
+    #[rstest]
+    fn An_OpenCircuitDetector_should_report_persistently_saturated_channels_as_open() -> Result<()> {
+
+        let mut testee = OpenCircuitDetector::new().with_persistence(2);
+
+        assert_that!(testee.status(Channel::Channel1), eq(SensorStatus::Unknown));
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(1.0)), ok(eq(&1.0)));
+        assert_that!(testee.status(Channel::Channel1), eq(SensorStatus::Connected));
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, saturated()), err(matches_pattern!(Error::IllegalValue { .. })));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, saturated()), err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(testee.status(Channel::Channel1), eq(SensorStatus::Open));
+        assert_that!(testee.status(Channel::Channel2), eq(SensorStatus::Unknown));
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(1.0)), err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(1.0)), ok(eq(&1.0)));
+        assert_that!(testee.status(Channel::Channel1), eq(SensorStatus::Connected));
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Err(Error::NotReady)), err(matches_pattern!(Error::NotReady)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_OpenCircuitDetector_should_report_channels_with_implausible_steps_as_open() -> Result<()> {
+
+        let mut testee = OpenCircuitDetector::new()
+            .with_persistence(2)
+            .with_max_step(10.0);
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel3, Ok(0.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel3, Ok(5.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel3, Ok(50.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel3, Ok(-50.0)), err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(testee.status(Channel::Channel3), eq(SensorStatus::Open));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_OpenCircuitDetector_should_report_jittering_channels_as_open() -> Result<()> {
+
+        let mut testee = OpenCircuitDetector::new()
+            .with_persistence(2)
+            .with_max_step(10.0)
+            .with_max_noise(2.0);
+
+        for value in [0.0, 5.0, 10.0, 9.0, 10.5] {
+            assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel2, Ok(value)), ok(eq(&value)));
+        }
+        assert_that!(testee.status(Channel::Channel2), eq(SensorStatus::Connected));
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel2, Ok(5.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel2, Ok(10.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel2, Ok(5.0)), err(matches_pattern!(Error::SensorOpen)));
+        assert_that!(testee.status(Channel::Channel2), eq(SensorStatus::Open));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn An_OpenCircuitDetector_should_ignore_non_finite_limits() -> Result<()> {
+
+        let mut testee = OpenCircuitDetector::new()
+            .with_persistence(1)
+            .with_max_step(f32::NAN)
+            .with_max_noise(f32::INFINITY);
+
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(0.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(100.0)), ok(anything()));
+        assert_that!(testee.evaluate::<ErrorKind>(Channel::Channel1, Ok(0.0)), ok(anything()));
+        assert_that!(testee.status(Channel::Channel1), eq(SensorStatus::Connected));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+use core::cell::RefCell;
+use core::future::poll_fn;
+use core::task::Poll;
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::waitqueue::MultiWakerRegistration;
+
+use crate::{Configuration, Error, MCP3424, OneShotMode};
+use crate::cfg::Cfg;
+use crate::mode::oneshot_cfg;
+
+/// A request for a single measurement, see [`RequestQueue`].
+///
+/// **Default:** A request has the priority `0` and no deadline.
+///
+#[derive(Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MeasurementRequest {
+    configuration: Configuration,
+    priority: u8,
+    deadline: Option<u64>,
+}
+
+impl MeasurementRequest {
+
+    pub fn new(configuration: &Configuration) -> Self {
+        Self {
+            configuration: configuration.clone(),
+            priority: 0,
+            deadline: None,
+        }
+    }
+
+    /// Sets the priority of the request. Requests with a higher priority are executed first.
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the deadline of the request in µs. Among requests of the same priority, the request
+    /// with the earliest deadline is executed first.
+    pub fn with_deadline(mut self, deadline_us: u64) -> Self {
+        self.deadline = Some(deadline_us);
+        self
+    }
+}
+
+/// A service executing measurement requests of several tasks one at a time.
+///
+/// The service takes a driver in [`OneShotMode`] and accepts up to `Q` pending
+/// [`MeasurementRequest`]s. The requests are executed one at a time in the order of their priority,
+/// their deadline and their arrival, so that urgent requests go ahead of background scans. If the
+/// queue is full, an [`Error::CapacityExceeded`] will be returned.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+/// use mcp3424::{MCP3424, Channel, Configuration, MeasurementRequest, OneShotMode, RequestQueue};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10100000]),
+///#     Transaction::read(0x68, vec![0, 1, 0b00100000, 0]),
+///# ]);
+///#
+/// let adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let queue = RequestQueue::<NoopRawMutex, _, _, _, 8>::new(adc);
+///
+///# async_std::task::block_on(async {
+/// let request = MeasurementRequest::new(&Configuration::default().with_channel(Channel::Channel2))
+///     .with_priority(10);
+///
+/// println!("Measured value: {:?}", queue.measure(&request).await);
+///# });
+///# queue.into_inner().into_inner().0.done();
+/// ```
+///
+pub struct RequestQueue<M: RawMutex, I2c, BusError, Delay, const Q: usize> {
+    driver: embassy_sync::mutex::Mutex<M, MCP3424<I2c, BusError, Delay, OneShotMode>>,
+    state: embassy_sync::blocking_mutex::Mutex<M, RefCell<State<Q>>>,
+}
+
+struct State<const Q: usize> {
+    entries: [Option<Entry>; Q],
+    sequence: u32,
+    busy: bool,
+    wakers: MultiWakerRegistration<Q>,
+}
+
+#[derive(Copy, Clone)]
+struct Entry {
+    id: u32,
+    priority: u8,
+    deadline: Option<u64>,
+}
+
+impl <const Q: usize> State<Q> {
+
+    /// Returns the id of the request to execute next.
+    fn head(&self) -> Option<u32> {
+        self.entries.iter()
+            .flatten()
+            .min_by_key(|entry| (u8::MAX - entry.priority, entry.deadline.unwrap_or(u64::MAX), entry.id))
+            .map(|entry| entry.id)
+    }
+
+    fn remove(&mut self, id: u32) {
+        for slot in self.entries.iter_mut() {
+            if matches!(slot, Some(entry) if entry.id == id) {
+                *slot = None;
+            }
+        }
+    }
+}
+
+impl <M, I2c, BusError, Delay, const Q: usize> RequestQueue<M, I2c, BusError, Delay, Q>
+where
+    M: RawMutex,
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    pub fn new(driver: MCP3424<I2c, BusError, Delay, OneShotMode>) -> Self {
+        Self {
+            driver: embassy_sync::mutex::Mutex::new(driver),
+            state: embassy_sync::blocking_mutex::Mutex::new(RefCell::new(State {
+                entries: [None; Q],
+                sequence: 0,
+                busy: false,
+                wakers: MultiWakerRegistration::new(),
+            })),
+        }
+    }
+
+    /// Returns the number of pending requests.
+    pub fn pending(&self) -> usize {
+        self.state.lock(|state| state.borrow().entries.iter().flatten().count())
+    }
+
+    /// Returns the driver.
+    pub fn into_inner(self) -> MCP3424<I2c, BusError, Delay, OneShotMode> {
+        self.driver.into_inner()
+    }
+
+    /// Queues the specified request, waits for its turn, then triggers a single conversion and
+    /// awaits the result.
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&self, request: &MeasurementRequest) -> Result<f32, Error<BusError>> {
+        self.do_measure(request).await
+    }
+
+    /// Queues the specified request, waits for its turn, then triggers a single conversion and
+    /// awaits the result.
+    #[cfg(feature = "uom")]
+    pub async fn measure(&self, request: &MeasurementRequest) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure(request).await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    async fn do_measure(&self, request: &MeasurementRequest) -> Result<f32, Error<BusError>> {
+
+        let cfg = oneshot_cfg(&request.configuration, Cfg::default());
+        let delay = request.configuration.conversion_time_us();
+
+        let mut ticket = self.enqueue(request)?;
+
+        poll_fn(|cx| self.state.lock(|state| {
+            let mut state = state.borrow_mut();
+            if !state.busy && state.head() == Some(ticket.id) {
+                state.busy = true;
+                state.remove(ticket.id);
+                Poll::Ready(())
+            }
+            else {
+                state.wakers.register(cx.waker());
+                Poll::Pending
+            }
+        })).await;
+
+        ticket.started = true;
+
+        let mut buffer = [0_u8; 4];
+        let mut driver = self.driver.lock().await;
+
+        driver.convert_single(&cfg, delay, &mut buffer).await
+    }
+
+    fn enqueue(&self, request: &MeasurementRequest) -> Result<Ticket<'_, M, Q>, Error<BusError>> {
+        self.state.lock(|state| {
+            let mut state = state.borrow_mut();
+            let id = state.sequence;
+            let slot = state.entries.iter_mut()
+                .find(|slot| slot.is_none())
+                .ok_or(Error::CapacityExceeded { capacity: Q })?;
+            *slot = Some(Entry { id, priority: request.priority, deadline: request.deadline });
+            state.sequence = state.sequence.wrapping_add(1);
+            Ok(Ticket { state: &self.state, id, started: false })
+        })
+    }
+}
+
+/// Removes a request from the queue when it has been executed or abandoned.
+struct Ticket<'a, M: RawMutex, const Q: usize> {
+    state: &'a embassy_sync::blocking_mutex::Mutex<M, RefCell<State<Q>>>,
+    id: u32,
+    started: bool,
+}
+
+impl <M: RawMutex, const Q: usize> Drop for Ticket<'_, M, Q> {
+
+    fn drop(&mut self) {
+        self.state.lock(|state| {
+            let mut state = state.borrow_mut();
+            if self.started {
+                state.busy = false;
+            }
+            else {
+                state.remove(self.id);
+            }
+            state.wakers.wake();
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Channel, Configuration, Error, MCP3424, MeasurementRequest, OneShotMode, RequestQueue};
+    use crate::test_util::YieldingDelay;
+
+    #[rstest]
+    async fn A_RequestQueue_should_execute_urgent_requests_first() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b11000000]),
+            Transaction::read(0x68, vec![0, 3, 0b01000000, 0]),
+            Transaction::write(0x68, vec![0b10100000]),
+            Transaction::read(0x68, vec![0, 2, 0b00100000, 0]),
+        ]);
+
+        let testee = RequestQueue::<NoopRawMutex, _, _, _, 4>::new(MCP3424::new(i2c, 0x68, YieldingDelay, OneShotMode::new(&Configuration::default())));
+
+        let first = MeasurementRequest::new(&Configuration::default());
+        let background = MeasurementRequest::new(&Configuration::default().with_channel(Channel::Channel2));
+        let urgent = MeasurementRequest::new(&Configuration::default().with_channel(Channel::Channel3))
+            .with_priority(10);
+
+        let (first, background, urgent) = futures::join!(
+            testee.measure(&first),
+            testee.measure(&background),
+            testee.measure(&urgent),
+        );
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(first, ok(eq(&ElectricPotential::new::<millivolt>(1.0))));
+            assert_that!(background, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+            assert_that!(urgent, ok(eq(&ElectricPotential::new::<millivolt>(3.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(first, ok(eq(&1.0)));
+            assert_that!(background, ok(eq(&2.0)));
+            assert_that!(urgent, ok(eq(&3.0)));
+        }
+        assert_that!(testee.pending(), eq(0));
+
+        testee.into_inner().i2c.done();
+
+        Ok(())
+    }
+
+    #[rstest]
+    async fn A_RequestQueue_should_reject_requests_exceeding_its_depth() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 2, 0b00000000, 0]),
+        ]);
+
+        let testee = RequestQueue::<NoopRawMutex, _, _, _, 1>::new(MCP3424::new(i2c, 0x68, YieldingDelay, OneShotMode::new(&Configuration::default())));
+
+        let request = MeasurementRequest::new(&Configuration::default());
+
+        let (executing, pending, rejected) = futures::join!(
+            testee.measure(&request),
+            testee.measure(&request),
+            testee.measure(&request),
+        );
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(executing, ok(eq(&ElectricPotential::new::<millivolt>(1.0))));
+            assert_that!(pending, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(executing, ok(eq(&1.0)));
+            assert_that!(pending, ok(eq(&2.0)));
+        }
+        assert_that!(rejected, err(matches_pattern!(Error::CapacityExceeded { capacity: eq(&1) })));
+
+        testee.into_inner().i2c.done();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{Clock, Error, MCP3424};
+
+/// A fixed-rate schedule for periodic measurements.
+///
+/// The schedule determines the points in time at which the driver triggers acquisitions. The
+/// deadlines are placed on a fixed grid of multiples of the period starting at the first
+/// measurement, so that delays of single measurements (jitter) do not accumulate. If a deadline has
+/// been missed by more than a period, the missed periods are skipped and counted as overruns.
+///
+/// Between the measurements the driver waits using its delay while the device stays in standby.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Clock, Configuration, OneShotMode, Schedule, Error};
+///
+///# struct Timer;
+///# impl Clock for Timer { fn now_us(&self) -> u64 { 0 } }
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let mut schedule = Schedule::new(Timer, 100_000);
+///
+///# let _: Result<(), Error<_>> = async_std::task::block_on(async {
+/// let sample = adc.measure_scheduled(&mut schedule).await?;
+/// println!("Measured value {:?} at {} µs", sample.value, sample.timestamp);
+///# Ok(())
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+pub struct Schedule<C: Clock> {
+    clock: C,
+    period: u64,
+    deadline: Option<u64>,
+    overruns: u32,
+}
+
+impl <C: Clock> Schedule<C> {
+
+    /// Creates a new schedule with the specified period in µs.
+    pub fn new(clock: C, period_us: u64) -> Self {
+        Self {
+            clock,
+            period: period_us.max(1),
+            deadline: None,
+            overruns: 0,
+        }
+    }
+
+    /// Returns the period in µs.
+    pub fn period_us(&self) -> u64 {
+        self.period
+    }
+
+    /// Returns the total number of missed periods.
+    pub fn overruns(&self) -> u32 {
+        self.overruns
+    }
+
+    /// Restarts the schedule, so that the next measurement is taken immediately.
+    pub fn reset(&mut self) {
+        self.deadline = None;
+        self.overruns = 0;
+    }
+
+    /// Returns the time to wait for the next deadline and the number of missed periods, and
+    /// advances the schedule by one period.
+    pub(crate) fn advance(&mut self) -> (u64, u32) {
+
+        let now = self.clock.now_us();
+        let deadline = *self.deadline.get_or_insert(now);
+
+        let missed = now.saturating_sub(deadline) / self.period;
+        let deadline = deadline + missed * self.period;
+
+        self.deadline = Some(deadline + self.period);
+
+        let missed = u32::try_from(missed).unwrap_or(u32::MAX);
+        self.overruns = self.overruns.saturating_add(missed);
+
+        (deadline.saturating_sub(now), missed)
+    }
+
+    pub(crate) fn now_us(&self) -> u64 {
+        self.clock.now_us()
+    }
+}
+
+/// A value measured according to a [`Schedule`].
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Scheduled<T> {
+    /// The measured value.
+    pub value: T,
+    /// The time in µs at which the acquisition has been triggered.
+    pub timestamp: u64,
+    /// The number of periods missed since the previous measurement.
+    pub missed: u32,
+}
+
+impl <T> Scheduled<T> {
+
+    /// Maps the measured value, keeping the timing information.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Scheduled<U> {
+        Scheduled {
+            value: f(self.value),
+            timestamp: self.timestamp,
+            missed: self.missed,
+        }
+    }
+}
+
+/// A schedule with an individual period per channel slot.
+///
+/// The schedule interleaves single conversions of the slots, so that each slot is measured at its
+/// own rate, e.g. a fast current channel and a slow temperature channel. The next slot to measure
+/// is always the one with the earliest deadline. Like a [`Schedule`], the deadlines of each slot
+/// are placed on a fixed grid, whereby missed periods are skipped and counted as overruns.
+///
+/// The rates actually achieved are tracked per slot, see [`Self::achieved_rate`].
+///
+pub struct RateSchedule<C: Clock, const N: usize> {
+    clock: C,
+    periods: [u64; N],
+    deadlines: Option<[u64; N]>,
+    overruns: [u32; N],
+    counts: [u32; N],
+    first: [u64; N],
+    last: [u64; N],
+}
+
+impl <C: Clock, const N: usize> RateSchedule<C, N> {
+
+    /// Creates a new schedule with the specified periods in µs, one per slot.
+    pub fn new(clock: C, periods_us: [u64; N]) -> Self {
+        Self {
+            clock,
+            periods: periods_us.map(|period| period.max(1)),
+            deadlines: None,
+            overruns: [0; N],
+            counts: [0; N],
+            first: [0; N],
+            last: [0; N],
+        }
+    }
+
+    /// Returns the period of the specified slot in µs.
+    pub fn period_us(&self, index: usize) -> Option<u64> {
+        self.periods.get(index).copied()
+    }
+
+    /// Returns the number of missed periods of the specified slot.
+    pub fn overruns(&self, index: usize) -> Option<u32> {
+        self.overruns.get(index).copied()
+    }
+
+    /// Returns the rate in Hz at which the specified slot has been measured so far, or `None` if
+    /// the slot has not been measured at least twice.
+    pub fn achieved_rate(&self, index: usize) -> Option<f32> {
+        let count = *self.counts.get(index)?;
+        let elapsed = self.last[index].saturating_sub(self.first[index]);
+        if count < 2 || elapsed == 0 {
+            None
+        }
+        else {
+            Some((count - 1) as f32 * 1_000_000.0 / elapsed as f32)
+        }
+    }
+
+    /// Restarts the schedule and clears the tracked rates.
+    pub fn reset(&mut self) {
+        self.deadlines = None;
+        self.overruns = [0; N];
+        self.counts = [0; N];
+    }
+
+    /// Returns the average time in µs the conversions require within the shortest period.
+    pub(crate) fn required_us(&self, conversion_times_us: &[u32; N]) -> (u64, u64) {
+        let shortest = self.periods.iter().copied().min().unwrap_or(u64::MAX);
+        let required = self.periods.iter()
+            .zip(conversion_times_us)
+            .map(|(period, time)| *time as u64 * shortest / period)
+            .sum();
+        (shortest, required)
+    }
+
+    /// Returns the slot with the earliest deadline, the time to wait for it and the number of
+    /// missed periods of the slot, and advances the slot by one period.
+    pub(crate) fn advance(&mut self) -> (usize, u64, u32) {
+
+        let now = self.clock.now_us();
+        let deadlines = self.deadlines.get_or_insert([now; N]);
+
+        let (index, deadline) = deadlines.iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|(_, deadline)| *deadline)
+            .unwrap_or((0, now));
+
+        let period = self.periods[index];
+        let missed = now.saturating_sub(deadline) / period;
+        let deadline = deadline + missed * period;
+
+        deadlines[index] = deadline + period;
+
+        let missed = u32::try_from(missed).unwrap_or(u32::MAX);
+        self.overruns[index] = self.overruns[index].saturating_add(missed);
+
+        (index, deadline.saturating_sub(now), missed)
+    }
+
+    /// Records a measurement of the specified slot at the specified time.
+    pub(crate) fn record(&mut self, index: usize, timestamp: u64) {
+        if self.counts[index] == 0 {
+            self.first[index] = timestamp;
+        }
+        self.last[index] = timestamp;
+        self.counts[index] = self.counts[index].saturating_add(1);
+    }
+
+    pub(crate) fn now_us(&self) -> u64 {
+        self.clock.now_us()
+    }
+}
+
+impl <I2c, BusError, Delay, Mode> MCP3424<I2c, BusError, Delay, Mode>
+where
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>,
+    Mode: crate::mode::Mode
+{
+    /// Waits for the specified time in µs.
+    pub(crate) async fn wait_us(&mut self, mut wait: u64) {
+        while wait > 0 {
+            let chunk = wait.min(u32::MAX as u64);
+            self.delay.delay_us(chunk as u32).await;
+            wait -= chunk;
+        }
+    }
+
+    /// Waits for the next deadline of the specified schedule.
+    ///
+    /// If the period of the schedule is shorter than the specified conversion time, an
+    /// [`Error::Overrun`] will be returned. Returns the timestamp after waiting and the number of
+    /// missed periods.
+    ///
+    pub(crate) async fn await_schedule<C: Clock>(&mut self, schedule: &mut Schedule<C>, conversion_time_us: u64) -> Result<(u64, u32), Error<BusError>> {
+
+        if schedule.period < conversion_time_us {
+            return Err(Error::Overrun { period: schedule.period, required: conversion_time_us })
+        }
+
+        let (wait, missed) = schedule.advance();
+
+        self.wait_us(wait).await;
+
+        Ok((schedule.now_us(), missed))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::cell::Cell;
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{RateSchedule, Schedule};
+    use crate::test_util::TestClock;
+
+    #[rstest]
+    fn A_Schedule_should_compensate_jitter_and_skip_missed_periods() -> Result<()> {
+
+        let time = Cell::new(1000);
+
+        let mut testee = Schedule::new(TestClock(&time), 100);
+
+        assert_that!(testee.advance(), eq((0, 0)));
+        time.set(1030);
+        assert_that!(testee.advance(), eq((70, 0)));
+        time.set(1220);
+        assert_that!(testee.advance(), eq((0, 0)));
+        time.set(1560);
+        assert_that!(testee.advance(), eq((0, 2)));
+        assert_that!(testee.overruns(), eq(2));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_RateSchedule_should_interleave_the_slots_according_to_their_periods() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = RateSchedule::new(TestClock(&time), [100, 300]);
+
+        let mut order = [0_usize; 8];
+
+        for index in order.iter_mut() {
+            let (slot, wait, missed) = testee.advance();
+            time.set(time.get() + wait);
+            testee.record(slot, time.get());
+            assert_that!(missed, eq(0));
+            *index = slot;
+        }
+
+        assert_that!(order, eq([0, 1, 0, 0, 0, 1, 0, 0]));
+        assert_that!(testee.achieved_rate(0), some(eq(10_000.0)));
+        assert_that!(testee.achieved_rate(1), some(eq(1_000_000.0 / 300.0)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_RateSchedule_should_tolerate_a_clock_going_backwards() -> Result<()> {
+
+        let time = Cell::new(1000);
+
+        let mut testee = RateSchedule::new(TestClock(&time), [100]);
+
+        let (slot, wait, _) = testee.advance();
+        testee.record(slot, time.get() + wait);
+        time.set(400);
+        let (slot, wait, missed) = testee.advance();
+        testee.record(slot, time.get());
+
+        assert_that!((wait, missed), eq((700, 0)));
+        assert_that!(testee.achieved_rate(0), none());
+
+        Ok(())
+    }
+}
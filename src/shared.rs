@@ -0,0 +1,123 @@
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::mutex::Mutex;
+use embedded_hal_async::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+/// A handle to an I2C bus shared by several drivers.
+///
+/// The bus is guarded by a [`Mutex`], which is locked for each transaction of the driver only. As
+/// the device keeps its configuration and converts on its own, the lock is not held across the
+/// write-delay-read sequence of a conversion, so that other devices can use the bus while the
+/// driver awaits the conversion time.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+/// use embassy_sync::mutex::Mutex;
+/// use mcp3424::{MCP3424, Configuration, OneShotMode, SharedI2c};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x69, vec![0b10000000]),
+///#     Transaction::read(0x69, vec![0, 2, 0, 0]),
+///# ]);
+///#
+/// let bus = Mutex::<NoopRawMutex, _>::new(i2c);
+///
+/// let mut adc_1 = MCP3424::new(SharedI2c::new(&bus), 0x68, Delay, OneShotMode::new(&Configuration::default()));
+/// let mut adc_2 = MCP3424::new(SharedI2c::new(&bus), 0x69, Delay, OneShotMode::new(&Configuration::default()));
+///
+///# async_std::task::block_on(async {
+/// println!("Value of the first device: {:?}", adc_1.measure().await);
+/// println!("Value of the second device: {:?}", adc_2.measure().await);
+///# });
+///# bus.into_inner().done();
+/// ```
+///
+pub struct SharedI2c<'a, M: RawMutex, Bus> {
+    bus: &'a Mutex<M, Bus>,
+}
+
+impl <'a, M: RawMutex, Bus> SharedI2c<'a, M, Bus> {
+
+    pub fn new(bus: &'a Mutex<M, Bus>) -> Self {
+        Self { bus }
+    }
+}
+
+impl <M: RawMutex, Bus: ErrorType> ErrorType for SharedI2c<'_, M, Bus> {
+    type Error = Bus::Error;
+}
+
+impl <M: RawMutex, Bus: I2c> I2c for SharedI2c<'_, M, Bus> {
+
+    async fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.lock().await.read(address, read).await
+    }
+
+    async fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        self.bus.lock().await.write(address, write).await
+    }
+
+    async fn write_read(&mut self, address: SevenBitAddress, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.lock().await.write_read(address, write, read).await
+    }
+
+    async fn transaction(&mut self, address: SevenBitAddress, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        self.bus.lock().await.transaction(address, operations).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embassy_sync::mutex::Mutex;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{Configuration, MCP3424, OneShotMode, SharedI2c};
+    use crate::test_util::YieldingDelay;
+
+    #[rstest]
+    async fn Several_MCP3424_should_share_a_bus_while_awaiting_conversions() -> Result<()> {
+
+        let bus = Mutex::<NoopRawMutex, _>::new(I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::write(0x69, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::read(0x69, vec![0, 2, 0b00000000, 0]),
+        ]));
+
+        let mut testee_1 = MCP3424::new(SharedI2c::new(&bus), 0x68, YieldingDelay, OneShotMode::new(&Configuration::default()));
+        let mut testee_2 = MCP3424::new(SharedI2c::new(&bus), 0x69, YieldingDelay, OneShotMode::new(&Configuration::default()));
+
+        let (result_1, result_2) = futures::join!(testee_1.measure(), testee_2.measure());
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(result_1, ok(eq(&ElectricPotential::new::<millivolt>(1.0))));
+            assert_that!(result_2, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(result_1, ok(eq(&1.0)));
+            assert_that!(result_2, ok(eq(&2.0)));
+        }
+
+        bus.into_inner().done();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::mutex::Mutex;
+
+use crate::{Channel, Configuration, Error, MCP3424, OneShotMode};
+use crate::cfg::Cfg;
+use crate::mode::oneshot_cfg;
+
+/// Arbiter serializing the conversions of several channel handles on one device.
+///
+/// The arbiter takes a driver in [`OneShotMode`] and splits it into one [`ChannelHandle`] per
+/// channel, see [`Self::split`]. Each handle has its own [`Configuration`] and can be used from a
+/// separate task. The arbiter holds the driver behind a [`Mutex`] which is locked for the entire
+/// conversion of a handle, so that the configuration of the handle is applied for each conversion.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+/// use mcp3424::{MCP3424, ChannelArbiter, Configuration, Gain, OneShotMode};
+///
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 1, 0, 0]),
+///#     Transaction::write(0x68, vec![0b10100011]),
+///#     Transaction::read(0x68, vec![0, 2, 0b00100011, 0]),
+///# ]);
+///#
+/// let adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let arbiter = ChannelArbiter::<NoopRawMutex, _, _, _>::new(adc);
+///
+/// let [mut current, mut temperature, _, _] = arbiter.split();
+///
+/// temperature.configure(&Configuration::default().with_gain(Gain::X8));
+///
+///# async_std::task::block_on(async {
+/// println!("Current: {:?}", current.measure().await);
+/// println!("Temperature: {:?}", temperature.measure().await);
+///# });
+///# arbiter.into_inner().into_inner().0.done();
+/// ```
+///
+pub struct ChannelArbiter<M: RawMutex, I2c, BusError, Delay> {
+    driver: Mutex<M, MCP3424<I2c, BusError, Delay, OneShotMode>>,
+}
+
+impl <M, I2c, BusError, Delay> ChannelArbiter<M, I2c, BusError, Delay>
+where
+    M: RawMutex,
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    pub fn new(driver: MCP3424<I2c, BusError, Delay, OneShotMode>) -> Self {
+        Self {
+            driver: Mutex::new(driver),
+        }
+    }
+
+    /// Returns one handle per channel, each configured with the default configuration.
+    pub fn split(&self) -> [ChannelHandle<'_, M, I2c, BusError, Delay>; 4] {
+        [Channel::Channel1, Channel::Channel2, Channel::Channel3, Channel::Channel4]
+            .map(|channel| ChannelHandle::new(self, channel))
+    }
+
+    /// Returns the driver.
+    pub fn into_inner(self) -> MCP3424<I2c, BusError, Delay, OneShotMode> {
+        self.driver.into_inner()
+    }
+}
+
+/// Handle to a single channel of a device shared via a [`ChannelArbiter`].
+pub struct ChannelHandle<'a, M: RawMutex, I2c, BusError, Delay> {
+    arbiter: &'a ChannelArbiter<M, I2c, BusError, Delay>,
+    channel: Channel,
+    cfg: Cfg,
+    delay: u32,
+}
+
+impl <'a, M, I2c, BusError, Delay> ChannelHandle<'a, M, I2c, BusError, Delay>
+where
+    M: RawMutex,
+    I2c: embedded_hal_async::i2c::I2c,
+    BusError: embedded_hal_async::i2c::Error,
+    Delay: embedded_hal_async::delay::DelayNs,
+    Error<BusError>: From<<I2c as embedded_hal_async::i2c::ErrorType>::Error>
+{
+    fn new(arbiter: &'a ChannelArbiter<M, I2c, BusError, Delay>, channel: Channel) -> Self {
+        let mut handle = Self {
+            arbiter,
+            channel,
+            cfg: Cfg::default(),
+            delay: 0,
+        };
+        handle.configure(&Configuration::default());
+        handle
+    }
+
+    /// Returns the channel of the handle.
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Updates the handle's configuration. The channel of the configuration is ignored in favour of
+    /// the handle's channel. The configuration is applied to the device on the next measure call.
+    pub fn configure(&mut self, configuration: &Configuration) {
+        self.cfg = oneshot_cfg(configuration, Cfg::default());
+        self.cfg.channel = self.channel;
+        self.delay = configuration.conversion_time_us();
+    }
+
+    /// Waits until the device is available, then triggers a single conversion of the handle's
+    /// channel and awaits the result.
+    #[cfg(not(feature = "uom"))]
+    pub async fn measure(&mut self) -> Result<f32, Error<BusError>> {
+        self.do_measure().await
+    }
+
+    /// Waits until the device is available, then triggers a single conversion of the handle's
+    /// channel and awaits the result.
+    #[cfg(feature = "uom")]
+    pub async fn measure(&mut self) -> Result<uom::si::f32::ElectricPotential, Error<BusError>> {
+        self.do_measure().await
+            .map(uom::si::f32::ElectricPotential::new::<uom::si::electric_potential::millivolt>)
+    }
+
+    async fn do_measure(&mut self) -> Result<f32, Error<BusError>> {
+        let mut buffer = [0_u8; 4];
+        let mut driver = self.arbiter.driver.lock().await;
+        driver.convert_single(&self.cfg, self.delay, &mut buffer).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2c, Transaction};
+    use googletest::prelude::*;
+    use rstest::rstest;
+    #[cfg(feature = "uom")]
+    use uom::si::electric_potential::millivolt;
+    #[cfg(feature = "uom")]
+    use uom::si::f32::ElectricPotential;
+
+    use crate::{ChannelArbiter, Configuration, Gain, MCP3424, OneShotMode};
+    use crate::test_util::YieldingDelay;
+
+    #[rstest]
+    async fn ChannelHandles_should_serialize_conversions_using_their_own_configurations() -> Result<()> {
+
+        let i2c = I2c::new(&[
+            Transaction::write(0x68, vec![0b10000000]),
+            Transaction::read(0x68, vec![0, 1, 0b00000000, 0]),
+            Transaction::write(0x68, vec![0b11000001]),
+            Transaction::read(0x68, vec![0, 4, 0b01000001, 0]),
+        ]);
+
+        let arbiter = ChannelArbiter::<NoopRawMutex, _, _, _>::new(MCP3424::new(i2c, 0x68, YieldingDelay, OneShotMode::new(&Configuration::default())));
+
+        let [mut handle_1, _, mut handle_3, _] = arbiter.split();
+
+        handle_3.configure(&Configuration::default().with_gain(Gain::X2));
+
+        let (result_1, result_3) = futures::join!(handle_1.measure(), handle_3.measure());
+
+        #[cfg(feature = "uom")]
+        {
+            assert_that!(result_1, ok(eq(&ElectricPotential::new::<millivolt>(1.0))));
+            assert_that!(result_3, ok(eq(&ElectricPotential::new::<millivolt>(2.0))));
+        }
+
+        #[cfg(not(feature = "uom"))]
+        {
+            assert_that!(result_1, ok(eq(&1.0)));
+            assert_that!(result_3, ok(eq(&2.0)));
+        }
+
+        arbiter.into_inner().i2c.done();
+
+        Ok(())
+    }
+}
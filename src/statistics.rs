@@ -0,0 +1,180 @@
+use crate::{Channel, Clock};
+
+/// Tracker of the minimum, the maximum and the peak-to-peak value of every channel.
+///
+/// The [`PeakHold`] is fed by the `measure_tracked` functions of the [`OneShotMode`], the
+/// [`ContinuousMode`] and the [`MultiShotMode`], or by [`Self::update`], and keeps [`Statistics`]
+/// per channel since the last reset. As the tracker is independent of the driver, it can be queried
+/// and reset between two measurements without interrupting the acquisition.
+///
+/// # Example
+///
+/// ```
+///# use embedded_hal_mock::eh1::i2c::Mock as I2C;
+///# use embedded_hal_mock::eh1::i2c::Transaction;
+///# use embedded_hal_mock::eh1::delay::NoopDelay as Delay;
+/// use mcp3424::{MCP3424, Channel, Clock, Configuration, OneShotMode, PeakHold};
+///
+///# struct Timer;
+///# impl Clock for Timer { fn now_us(&self) -> u64 { 0 } }
+///# let mut i2c = I2C::new(&[
+///#     Transaction::write(0x68, vec![0b10000000]),
+///#     Transaction::read(0x68, vec![0, 2, 0, 0]),
+///# ]);
+///#
+/// let mut adc = MCP3424::new(i2c, 0x68, Delay, OneShotMode::new(&Configuration::default()));
+///
+/// let mut peak_hold = PeakHold::new(Timer);
+///
+///# async_std::task::block_on(async {
+/// let _ = adc.measure_tracked(&mut peak_hold).await;
+///
+/// if let Some(statistics) = peak_hold.statistics(Channel::Channel1) {
+///     println!("Peak-to-peak value: {} mV", statistics.peak_to_peak());
+/// }
+///# });
+///# adc.into_inner().0.done();
+/// ```
+///
+/// [`OneShotMode`]: crate::OneShotMode
+/// [`ContinuousMode`]: crate::ContinuousMode
+/// [`MultiShotMode`]: crate::MultiShotMode
+///
+pub struct PeakHold<C: Clock> {
+    clock: C,
+    statistics: [Option<Statistics>; 4],
+}
+
+/// The statistics of a channel tracked by a [`PeakHold`].
+///
+/// Values are specified in mV and timestamps in µs according to the tracker's [`Clock`].
+///
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Statistics {
+    pub minimum: f32,
+    pub minimum_timestamp: u64,
+    pub maximum: f32,
+    pub maximum_timestamp: u64,
+    /// The number of samples since the last reset.
+    pub count: u32,
+    /// The timestamp of the first sample since the last reset.
+    pub since: u64,
+}
+
+impl Statistics {
+
+    /// Returns the difference between the maximum and the minimum.
+    pub fn peak_to_peak(&self) -> f32 {
+        self.maximum - self.minimum
+    }
+}
+
+impl <C: Clock> PeakHold<C> {
+
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            statistics: [None; 4],
+        }
+    }
+
+    /// Returns the statistics of the specified channel, if there has been a sample since the last
+    /// reset.
+    pub fn statistics(&self, channel: Channel) -> Option<Statistics> {
+        self.statistics[channel.mask() as usize]
+    }
+
+    /// Discards the statistics of all channels.
+    pub fn reset(&mut self) {
+        self.statistics = [None; 4];
+    }
+
+    /// Discards the statistics of the specified channel.
+    pub fn reset_channel(&mut self, channel: Channel) {
+        self.statistics[channel.mask() as usize] = None;
+    }
+
+    /// Adds the specified value to the statistics of the specified channel.
+    #[cfg(not(feature = "uom"))]
+    pub fn update(&mut self, channel: Channel, value: f32) {
+        self.record(channel, value)
+    }
+
+    /// Adds the specified value to the statistics of the specified channel.
+    #[cfg(feature = "uom")]
+    pub fn update(&mut self, channel: Channel, value: uom::si::f32::ElectricPotential) {
+        self.record(channel, value.get::<uom::si::electric_potential::millivolt>())
+    }
+
+    pub(crate) fn record(&mut self, channel: Channel, value: f32) {
+        let timestamp = self.clock.now_us();
+        let statistics = self.statistics[channel.mask() as usize].get_or_insert(Statistics {
+            minimum: value,
+            minimum_timestamp: timestamp,
+            maximum: value,
+            maximum_timestamp: timestamp,
+            count: 0,
+            since: timestamp,
+        });
+        if value < statistics.minimum {
+            statistics.minimum = value;
+            statistics.minimum_timestamp = timestamp;
+        }
+        if value > statistics.maximum {
+            statistics.maximum = value;
+            statistics.maximum_timestamp = timestamp;
+        }
+        statistics.count = statistics.count.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::cell::Cell;
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::{Channel, PeakHold, Statistics};
+    use crate::test_util::TestClock;
+
+    #[rstest]
+    fn A_PeakHold_should_track_the_extremes_of_each_channel_until_reset() -> Result<()> {
+
+        let time = Cell::new(0);
+
+        let mut testee = PeakHold::new(TestClock(&time));
+
+        for (timestamp, value) in [(10, 5.0), (20, -3.0), (30, 8.0), (40, 1.0)] {
+            time.set(timestamp);
+            testee.record(Channel::Channel2, value);
+        }
+
+        assert_that!(testee.statistics(Channel::Channel2), some(matches_pattern!(Statistics {
+            minimum: eq(-3.0),
+            minimum_timestamp: eq(20),
+            maximum: eq(8.0),
+            maximum_timestamp: eq(30),
+            count: eq(4),
+            since: eq(10),
+        })));
+        assert_that!(testee.statistics(Channel::Channel2).map(|statistics| statistics.peak_to_peak()), some(eq(11.0)));
+        assert_that!(testee.statistics(Channel::Channel1), none());
+
+        testee.reset_channel(Channel::Channel2);
+        time.set(50);
+        testee.record(Channel::Channel2, 2.0);
+
+        assert_that!(testee.statistics(Channel::Channel2), some(matches_pattern!(Statistics {
+            minimum: eq(2.0),
+            maximum: eq(2.0),
+            count: eq(1),
+            since: eq(50),
+        })));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! Helpers shared by the unit tests.
+
+use core::cell::Cell;
+
+use crate::Clock;
+
+/// A clock which returns the time set on the referenced cell.
+pub(crate) struct TestClock<'a>(pub(crate) &'a Cell<u64>);
+
+impl Clock for TestClock<'_> {
+    fn now_us(&self) -> u64 {
+        self.0.get()
+    }
+}
+
+/// A clock which advances by a fixed, possibly negative step each time it gets read.
+pub(crate) struct StepClock<'a>(pub(crate) &'a Cell<u64>, pub(crate) i64);
+
+impl Clock for StepClock<'_> {
+    fn now_us(&self) -> u64 {
+        self.0.set(self.0.get().wrapping_add_signed(self.1));
+        self.0.get()
+    }
+}
+
+/// A clock which always returns the same time.
+pub(crate) struct FixedClock(pub(crate) u64);
+
+impl Clock for FixedClock {
+    fn now_us(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A delay which yields once, so that other tasks can run meanwhile, e.g. use the bus.
+#[cfg(feature = "embassy")]
+pub(crate) struct YieldingDelay;
+
+#[cfg(feature = "embassy")]
+impl embedded_hal_async::delay::DelayNs for YieldingDelay {
+    async fn delay_ns(&mut self, _: u32) {
+        async_std::task::yield_now().await
+    }
+}
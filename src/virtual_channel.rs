@@ -0,0 +1,177 @@
+use crate::Error;
+
+/// A channel whose value is derived from the values of physical channels.
+///
+/// A [`VirtualChannel`] combines two [`Operand`]s with an [`Operator`] and applies a scale and an
+/// offset to the result: `(lhs <operator> rhs) * scale + offset`. Channel operands refer to the
+/// position of a value within the measured values, e.g. the index of the corresponding
+/// [`Configuration`] passed to the [`MultiShotMode`].
+///
+/// Virtual channels are evaluated on the values in millivolts and always yield a plain `f32`, as
+/// the unit of a derived value depends on the expression. A division by a zero operand yields an
+/// [`Error::DivisionByZero`] instead of an infinite or NaN value.
+///
+/// # Example
+///
+/// ```
+/// use mcp3424::VirtualChannel;
+///
+/// let difference = VirtualChannel::difference(0, 1);
+/// let ratio = VirtualChannel::quotient(2, 3);
+/// let scaled_sum = VirtualChannel::sum(0, 1).with_scale(0.5);
+/// ```
+///
+/// # See also
+/// [`Operand`], [`Operator`]
+///
+/// [`Configuration`]: crate::Configuration
+/// [`MultiShotMode`]: crate::MultiShotMode
+///
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VirtualChannel {
+    pub lhs: Operand,
+    pub operator: Operator,
+    pub rhs: Operand,
+    pub scale: f32,
+    pub offset: f32,
+}
+
+impl VirtualChannel {
+
+    pub fn new(lhs: Operand, operator: Operator, rhs: Operand) -> Self {
+        Self { lhs, operator, rhs, scale: 1.0, offset: 0.0 }
+    }
+
+    /// Creates a virtual channel computing `values[lhs] + values[rhs]`.
+    pub fn sum(lhs: usize, rhs: usize) -> Self {
+        Self::new(Operand::Channel(lhs), Operator::Add, Operand::Channel(rhs))
+    }
+
+    /// Creates a virtual channel computing `values[lhs] - values[rhs]`.
+    pub fn difference(lhs: usize, rhs: usize) -> Self {
+        Self::new(Operand::Channel(lhs), Operator::Subtract, Operand::Channel(rhs))
+    }
+
+    /// Creates a virtual channel computing `values[lhs] * values[rhs]`.
+    pub fn product(lhs: usize, rhs: usize) -> Self {
+        Self::new(Operand::Channel(lhs), Operator::Multiply, Operand::Channel(rhs))
+    }
+
+    /// Creates a virtual channel computing `values[lhs] / values[rhs]`.
+    pub fn quotient(lhs: usize, rhs: usize) -> Self {
+        Self::new(Operand::Channel(lhs), Operator::Divide, Operand::Channel(rhs))
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Evaluates this virtual channel on the specified values.
+    ///
+    /// Returns an [`Error::InvalidIndex`] for the first operand referring to a non-existing value
+    /// and an [`Error::DivisionByZero`] if the divisor is zero.
+    pub(crate) fn evaluate<BusError>(&self, values: &[f32]) -> Result<f32, Error<BusError>>
+    where
+        BusError: embedded_hal_async::i2c::Error
+    {
+        let resolve = |operand: &Operand| operand.resolve(values)
+            .map_err(|index| Error::InvalidIndex { index, len: values.len() });
+        let lhs = resolve(&self.lhs)?;
+        let rhs = resolve(&self.rhs)?;
+        let value = match self.operator {
+            Operator::Add => lhs + rhs,
+            Operator::Subtract => lhs - rhs,
+            Operator::Multiply => lhs * rhs,
+            Operator::Divide if rhs == 0.0 => return Err(Error::DivisionByZero),
+            Operator::Divide => lhs / rhs,
+        };
+        Ok(value * self.scale + self.offset)
+    }
+}
+
+/// An operand of a [`VirtualChannel`].
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Operand {
+    /// Refers to the value at the specified index.
+    Channel(usize),
+    /// A constant value.
+    Constant(f32),
+}
+
+impl Operand {
+
+    fn resolve(&self, values: &[f32]) -> Result<f32, usize> {
+        match self {
+            Operand::Channel(index) => values.get(*index).copied().ok_or(*index),
+            Operand::Constant(value) => Ok(*value),
+        }
+    }
+}
+
+/// An arithmetic operator of a [`VirtualChannel`].
+#[derive(Copy, Clone)]
+#[cfg_attr(any(feature = "fmt", test), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use embedded_hal::i2c::ErrorKind;
+
+    use crate::Error;
+    use crate::virtual_channel::{Operand, Operator, VirtualChannel};
+
+    #[rstest]
+    #[case(VirtualChannel::sum(0, 1), 6.0)]
+    #[case(VirtualChannel::difference(0, 1), 2.0)]
+    #[case(VirtualChannel::product(0, 1), 8.0)]
+    #[case(VirtualChannel::quotient(0, 1), 2.0)]
+    #[case(VirtualChannel::sum(0, 1).with_scale(0.5), 3.0)]
+    #[case(VirtualChannel::difference(1, 0).with_offset(1.0), -1.0)]
+    #[case(VirtualChannel::new(Operand::Channel(2), Operator::Multiply, Operand::Constant(3.0)), 3.0)]
+    fn A_VirtualChannel_should_evaluate_its_expression(
+        #[case] channel: VirtualChannel,
+        #[case] expected: f32
+    ) -> Result<()> {
+
+        assert_that!(channel.evaluate::<ErrorKind>(&[4.0, 2.0, 1.0]), ok(eq(&expected)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_VirtualChannel_should_return_the_index_of_a_non_existing_value() -> Result<()> {
+
+        assert_that!(VirtualChannel::difference(0, 3).evaluate::<ErrorKind>(&[4.0, 2.0, 1.0]), err(matches_pattern!(Error::InvalidIndex { index: eq(&3), len: eq(&3) })));
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn A_VirtualChannel_should_return_an_error_on_a_division_by_zero() -> Result<()> {
+
+        assert_that!(VirtualChannel::quotient(0, 1).evaluate::<ErrorKind>(&[4.0, 0.0]), err(matches_pattern!(Error::DivisionByZero)));
+        assert_that!(VirtualChannel::new(Operand::Channel(0), Operator::Divide, Operand::Constant(0.0)).evaluate::<ErrorKind>(&[4.0]), err(matches_pattern!(Error::DivisionByZero)));
+
+        Ok(())
+    }
+}